@@ -0,0 +1,79 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mutsolver_core::{attempt::Attempt, Answer, Answers, Dict, Game, Options, Test, TestSuite};
+
+/// Build a dict of pseudo-random words sharing their first letter, as in a
+/// game with a revealed first letter.
+fn bench_dict(size: usize) -> Dict {
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (b'A' + (seed % 26) as u8) as char
+    };
+
+    let mut words: Vec<String> = (0..size)
+        .map(|_| std::iter::once('M').chain((0..6).map(|_| next())).collect())
+        .collect();
+    words.sort();
+    words.dedup();
+    Dict::new(words, vec![]).unwrap()
+}
+
+/// Known answers after a couple of attempts: a few tests answered Yes or No.
+fn known_answers(tests: &TestSuite) -> Answers {
+    tests
+        .iter()
+        .enumerate()
+        .map(|(index, _)| match index % 50 {
+            0 => Answer::No,
+            25 => Answer::Yes,
+            _ => Answer::Unknown,
+        })
+        .collect()
+}
+
+fn bench_filter(c: &mut Criterion) {
+    let dict = bench_dict(8192);
+    let tests = Test::for_dict(&dict, &Options::default());
+    let dict_answers = Answers::of_dict(&dict, &tests);
+    let known = known_answers(&tests);
+
+    // Former storage: answers of each word to all tests
+    let word_answers: Vec<Answers> = (0..dict_answers.len())
+        .map(|word| dict_answers.word(word))
+        .collect();
+
+    let mut group = c.benchmark_group("filter");
+    group.bench_function("per word", |b| {
+        b.iter(|| {
+            word_answers
+                .iter()
+                .filter(|answers| {
+                    black_box(&known)
+                        .iter()
+                        .zip(answers.iter())
+                        .all(|(known, answer)| (*known + *answer).is_ok())
+                })
+                .count()
+        })
+    });
+    group.bench_function("per test bitset", |b| {
+        b.iter(|| dict_answers.compatible(black_box(&known)).len())
+    });
+    group.finish();
+}
+
+fn bench_guess_next(c: &mut Criterion) {
+    let dict = bench_dict(2048);
+    let options = Options::default();
+    let mut game = Game::new(&dict, &options);
+    let guess = dict.answers[0].clone();
+    game.add(Attempt::from_answer(&guess, &dict.answers[1]).unwrap())
+        .unwrap();
+
+    c.bench_function("guess_next", |b| b.iter(|| game.guess_next().unwrap()));
+}
+
+criterion_group!(benches, bench_filter, bench_guess_next);
+criterion_main!(benches);
@@ -1,10 +1,11 @@
 use crate::errors::Error;
 use crate::{Dict, TestSuite};
+use serde::{Deserialize, Serialize};
 
 /// Structure containing answers for a test suite
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Answer {
     Unknown,
     Yes,
@@ -31,23 +32,183 @@ impl std::ops::Add<Self> for Answer {
     }
 }
 
-// TODO
-// Implement Answer addition => X + X = Ok(X)
-//                           => Unknown + X = Ok(X)
-//                           => Yes + No = Err(InconsistentAnswers)
-// On a game, compute answers for each attempt and merge these
-// answers using this addition rule. This gives:
-//  * which tests have been answered
-//  * what is the answer of these tests for the game solution
-// NB: HasPrefix/Suffix can be answered by combining multiple attempts
-//     even if no single attempt answered the test. Think of a way to
-//     postprocess the list of answers to answer Unknown tests by
-//     combining results from other resolved tests.
-
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Answers(pub Vec<Answer>);
 
-pub type DictAnswers = Vec<Answers>;
+/// Set of word indices of a dict, packed as bits of 64-word blocks so that
+/// sets can be combined a block at a time.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct WordSet(Vec<u64>);
+
+impl WordSet {
+    /// Create an empty set able to hold words up to a dict size.
+    pub fn empty(size: usize) -> Self {
+        Self(vec![0; size.div_ceil(64)])
+    }
+
+    /// Create a set holding all words of a dict size.
+    pub fn full(size: usize) -> Self {
+        let mut set = Self(vec![u64::MAX; size.div_ceil(64)]);
+        if !size.is_multiple_of(64) {
+            *set.0.last_mut().unwrap() = (1 << (size % 64)) - 1;
+        }
+        set
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0
+            .get(index / 64)
+            .is_some_and(|block| block & (1 << (index % 64)) != 0)
+    }
+
+    /// Number of words in the set.
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|block| block.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|block| *block == 0)
+    }
+
+    /// Keep only the words also in another set.
+    pub fn intersect(&mut self, other: &Self) {
+        for (block, other) in self.0.iter_mut().zip(other.0.iter()) {
+            *block &= other;
+        }
+    }
+
+    /// Remove the words in another set.
+    pub fn subtract(&mut self, other: &Self) {
+        for (block, other) in self.0.iter_mut().zip(other.0.iter()) {
+            *block &= !other;
+        }
+    }
+
+    /// Number of words in both sets, without building their intersection.
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(lhs, rhs)| (lhs & rhs).count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the indices of the words in both sets, in ascending order.
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = usize> + 'a {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .enumerate()
+            .flat_map(|(block_index, (lhs, rhs))| Self::indices(block_index, lhs & rhs))
+    }
+
+    /// Iterate over the indices of the words in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(block_index, block)| Self::indices(block_index, *block))
+    }
+
+    fn indices(block_index: usize, mut block: u64) -> impl Iterator<Item = usize> {
+        std::iter::from_fn(move || {
+            if block == 0 {
+                return None;
+            }
+            let bit = block.trailing_zeros() as usize;
+            block &= block - 1;
+            Some(block_index * 64 + bit)
+        })
+    }
+}
+
+impl std::iter::FromIterator<usize> for WordSet {
+    /// Collect word indices, the set being sized to the highest one.
+    fn from_iter<I: std::iter::IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::default();
+        for index in iter {
+            if index / 64 >= set.0.len() {
+                set.0.resize(index / 64 + 1, 0);
+            }
+            set.insert(index);
+        }
+        set
+    }
+}
+
+/// Answers of each word of a dict to the tests of a suite, stored for each
+/// test as the set of words answering Yes.
+///
+/// Words compatible with known answers are then found by intersecting sets,
+/// 64 words at a time.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DictAnswers {
+    /// Number of words of the dict
+    size: usize,
+    /// Words answering Yes, for each test
+    yes: Vec<WordSet>,
+}
+
+impl DictAnswers {
+    /// Number of words of the dict.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Check whether these answers were computed for each word of a dict and
+    /// each test of a suite.
+    pub fn is_of(&self, dict: &Dict, tests: &TestSuite) -> bool {
+        self.size == dict.len()
+            && self.yes.len() == tests.len()
+            && self
+                .yes
+                .iter()
+                .all(|set| set.0.len() == self.size.div_ceil(64))
+    }
+
+    /// Words answering Yes to the test at given index.
+    pub fn yes(&self, test: usize) -> &WordSet {
+        &self.yes[test]
+    }
+
+    /// Answer of the word at given index to the test at given index.
+    pub fn get(&self, word: usize, test: usize) -> Answer {
+        match self.yes[test].contains(word) {
+            true => Answer::Yes,
+            false => Answer::No,
+        }
+    }
+
+    /// Answers of the word at given index to all tests.
+    pub fn word(&self, word: usize) -> Answers {
+        (0..self.yes.len())
+            .map(|test| self.get(word, test))
+            .collect()
+    }
+
+    /// Words whose answers are compatible with known answers: words
+    /// answering Yes to every test known to be Yes, and No to every test
+    /// known to be No.
+    pub fn compatible(&self, known_answers: &Answers) -> WordSet {
+        let mut words = WordSet::full(self.size);
+        for (answer, yes) in known_answers.iter().zip(self.yes.iter()) {
+            match answer {
+                Answer::Unknown => (),
+                Answer::Yes => words.intersect(yes),
+                Answer::No => words.subtract(yes),
+            }
+        }
+        words
+    }
+}
 
 impl std::iter::FromIterator<Answer> for Answers {
     fn from_iter<I: std::iter::IntoIterator<Item = Answer>>(iter: I) -> Self {
@@ -69,13 +230,26 @@ impl Answers {
 
     /// Generate answers for all tests in a test-suite for each word of a dict.
     pub fn of_dict(dict: &Dict, tests: &TestSuite) -> DictAnswers {
-        let mut dict_answers = DictAnswers::with_capacity(dict.len());
+        let words: Vec<&String> = dict.answers.iter().chain(dict.allowed.iter()).collect();
+        let mut yes = Vec::with_capacity(tests.len());
 
-        dict.par_iter()
-            .map(|word| Answers::of_word(word, tests))
-            .collect_into_vec(&mut dict_answers);
+        tests
+            .par_iter()
+            .map(|test| {
+                let mut set = WordSet::empty(words.len());
+                for (index, word) in words.iter().enumerate() {
+                    if test.run(word) {
+                        set.insert(index);
+                    }
+                }
+                set
+            })
+            .collect_into_vec(&mut yes);
 
-        dict_answers
+        DictAnswers {
+            size: words.len(),
+            yes,
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::answers::Answer;
 use crate::errors::Error;
-use crate::state::{State, States};
+use crate::pattern::Pattern;
+use crate::state::{Alphabet, State, States};
 use crate::tests::Test;
 
 /// An attempt represent as word tested in a game and the state of each
@@ -10,6 +11,56 @@ pub struct Attempt<'a>(pub &'a str, pub States);
 /// Attempts contains all the attempts of a given game
 pub type Attempts<'a> = Vec<Attempt<'a>>;
 
+/// Alphabets recognized in shared result grids.
+const GRID_ALPHABETS: [Alphabet; 3] = [Alphabet::SUTOM, Alphabet::WORDLE, Alphabet::WORDLE_LIGHT];
+
+/// Parse a result grid shared by a player, given the words attempted.
+///
+/// Lines which are not made of squares (title, score, link...) are ignored,
+/// remaining rows being matched with attempted words in order.
+pub fn from_grid<'a>(grid: &str, words: &[&'a str]) -> Result<Attempts<'a>, Error> {
+    let rows: Vec<(String, &Alphabet)> = grid
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(|c| !c.is_whitespace() && *c != '\u{FE0F}')
+                .collect::<String>()
+        })
+        .filter(|row| !row.is_empty())
+        .filter_map(|row| {
+            GRID_ALPHABETS
+                .iter()
+                .find(|alphabet| row.chars().all(|symbol| alphabet.state(symbol).is_some()))
+                .map(|alphabet| (row, alphabet))
+        })
+        .collect();
+
+    if rows.len() != words.len() {
+        return Err(Error::InvalidGridSize(words.len(), rows.len()));
+    }
+
+    words
+        .iter()
+        .zip(rows.iter())
+        .map(|(word, (row, alphabet))| Attempt::from_pattern_with(word, row, alphabet))
+        .collect()
+}
+
+/// Render attempts as a grid of squares which can be shared.
+pub fn to_grid(attempts: &[Attempt], alphabet: &Alphabet) -> String {
+    attempts
+        .iter()
+        .map(|attempt| {
+            attempt
+                .1
+                .iter()
+                .map(|state| alphabet.symbol(state))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
 impl<'a, 'b> Attempt<'a> {
     pub fn from_answer(attempt: &'a str, answer: &'b str) -> Result<Self, Error> {
         use std::collections::HashMap;
@@ -50,7 +101,7 @@ impl<'a, 'b> Attempt<'a> {
             .zip(attempt.chars())
             .filter(|(sta, _)| **sta != State::Yes)
         {
-            if let Some(count) = acc.get_mut(&c) {
+            if let Some(count) = acc.get_mut(&c).filter(|count| **count > 0) {
                 *sta = State::Meh;
                 *count -= 1;
             }
@@ -59,6 +110,52 @@ impl<'a, 'b> Attempt<'a> {
         Ok(Attempt(attempt, states))
     }
 
+    /// Build an attempt from the pattern typed by a user, using the default
+    /// alphabet (R: red, Y: yellow, B: blue).
+    pub fn from_pattern(attempt: &'a str, pattern: &'b str) -> Result<Self, Error> {
+        Self::from_pattern_with(attempt, pattern, &Alphabet::default())
+    }
+
+    /// Build an attempt from the pattern typed by a user with a given alphabet.
+    pub fn from_pattern_with(
+        attempt: &'a str,
+        pattern: &'b str,
+        alphabet: &Alphabet,
+    ) -> Result<Self, Error> {
+        let size = attempt.chars().count();
+        if pattern.chars().count() != size {
+            return Err(Error::InvalidPatternSize(
+                size,
+                pattern.chars().count(),
+                pattern.to_owned(),
+            ));
+        }
+
+        let states = pattern
+            .chars()
+            .map(|symbol| {
+                alphabet
+                    .state(symbol)
+                    .ok_or_else(|| Error::UnknownSymbol(symbol, pattern.to_owned()))
+            })
+            .collect::<Result<States, Error>>()?;
+
+        Ok(Attempt(attempt, states))
+    }
+
+    /// Return the states of this attempt packed as a pattern.
+    pub fn pattern(&self) -> Result<Pattern, Error> {
+        Pattern::try_from(&self.1)
+    }
+
+    /// Check whether a given answer would have produced the states of this attempt.
+    pub fn is_compatible(&self, answer: &str) -> bool {
+        match Attempt::from_answer(self.0, answer) {
+            Ok(attempt) => attempt.1 == self.1,
+            Err(_) => false,
+        }
+    }
+
     pub fn answers(&self, test: &Test) -> Result<Answer, Error> {
         let states = &self.1;
         match test {
@@ -0,0 +1,423 @@
+use mutsolver_core::{
+    attempt::{to_grid, Attempt},
+    dict::{read_lists, validate},
+    dict_set::lists_of_size,
+    simulate,
+    state::Alphabet,
+    DecisionTree, Dict, DictSet, FeedbackMatrix, Game, Guess, NormalizationReport, OpeningBook,
+    Options, Scoring, TextOptions,
+};
+use std::io::{BufRead, Write};
+
+const USAGE: &str = "Usage: mutsolver <COMMAND> <DICT> [OPTIONS]
+
+DICT is either a JSON dictionary, or a plain-text list of answers (one word per
+line, lines starting with # being ignored).
+
+Commands:
+    play      Interactive solver: suggests a word at each turn, then reads the
+              word actually played and the feedback shown by the game
+    simulate  Play every answer of the dictionary and report statistics
+    tree      Export the tree of guesses for every answer as JSON
+    book      Export the guesses of the first two turns as JSON, to be
+              loaded with --book
+    check     Report every error of the dictionary
+
+Options:
+    --allowed <FILE>                       Plain-text list of allowed words
+    --skip-invalid                         Skip invalid lines of plain-text lists
+    --size <N>                             Only keep words of N letters from a
+                                           dictionary of mixed sizes
+    --normalize                            Strip accents and expand ligatures of
+                                           plain-text lists (e.g. ŒUVRÉ -> OEUVRE)
+    --scoring <heuristic|entropy|minimax>  Strategy used to rank guesses
+    --alphabet <colors|digits|signs>       Symbols used to type feedbacks
+    --reveal <POSITION:LETTER>             Letter revealed before first attempt
+                                           (e.g. 0:A for Sutom first letter)
+    --hard                                 Hard mode: reuse all hints
+    --max-attempts <N>                     Attempts before a simulated game is
+                                           lost (default: 6)
+    --verbose                              Show guesses of each simulated game,
+                                           and words changed by normalization
+    --optimal                              Export the tree minimizing the
+                                           expected number of attempts (slow)
+    --dot                                  Export tree as Graphviz DOT
+    --book <FILE>                          Opening book computed with the same
+                                           options";
+
+const HELP: &str = "Commands:
+    <WORD> <FEEDBACK>  Add played word and its feedback (e.g. ARBRE RBYBB)
+    undo               Remove last attempt
+    candidates         Show answers compatible with attempts
+    grid               Show attempts as a shareable grid
+    help               Show this help
+    quit               Exit solver";
+
+/// Settings of the interactive solver and of simulations.
+struct Settings {
+    dict: String,
+    allowed: Option<String>,
+    text: TextOptions,
+    size: Option<usize>,
+    options: Options,
+    alphabet: Alphabet,
+    max_attempts: usize,
+    verbose: bool,
+    optimal: bool,
+    dot: bool,
+    book: Option<String>,
+}
+
+fn parse_settings(args: &[String]) -> Result<Settings, String> {
+    let mut args = args.iter();
+    let dict = args.next().ok_or("Missing dictionary path")?.to_owned();
+    let mut settings = Settings {
+        dict,
+        allowed: None,
+        text: TextOptions::default(),
+        size: None,
+        options: Options::default(),
+        alphabet: Alphabet::default(),
+        max_attempts: 6,
+        verbose: false,
+        optimal: false,
+        dot: false,
+        book: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--allowed" => settings.allowed = Some(value()?.to_owned()),
+            "--skip-invalid" => settings.text.skip_invalid = true,
+            "--normalize" => settings.text.normalize = true,
+            "--size" => {
+                let size = value()?;
+                settings.size = Some(
+                    size.parse()
+                        .map_err(|_| format!("Invalid size of words '{}'", size))?,
+                );
+            }
+            "--scoring" => {
+                settings.options.scoring = match value()?.as_str() {
+                    "heuristic" => Scoring::Heuristic,
+                    "entropy" => Scoring::Entropy,
+                    "minimax" => Scoring::Minimax,
+                    other => return Err(format!("Unknown scoring '{}'", other)),
+                }
+            }
+            "--alphabet" => {
+                settings.alphabet = match value()?.as_str() {
+                    "colors" => Alphabet::COLORS,
+                    "digits" => Alphabet::DIGITS,
+                    "signs" => Alphabet::SIGNS,
+                    other => return Err(format!("Unknown alphabet '{}'", other)),
+                }
+            }
+            "--reveal" => {
+                let reveal = value()?;
+                let (position, letter) = reveal
+                    .split_once(':')
+                    .and_then(|(position, letter)| {
+                        let mut letters = letter.chars();
+                        match (position.parse().ok(), letters.next(), letters.next()) {
+                            (Some(position), Some(letter), None) => {
+                                Some((position, letter.to_ascii_uppercase()))
+                            }
+                            _ => None,
+                        }
+                    })
+                    .ok_or(format!("Invalid revealed letter '{}'", reveal))?;
+                settings.options.revealed.push((position, letter));
+            }
+            "--hard" => settings.options.hard_mode = true,
+            "--max-attempts" => {
+                let max_attempts = value()?;
+                settings.max_attempts = max_attempts
+                    .parse()
+                    .map_err(|_| format!("Invalid number of attempts '{}'", max_attempts))?;
+            }
+            "--verbose" => settings.verbose = true,
+            "--optimal" => settings.optimal = true,
+            "--dot" => settings.dot = true,
+            "--book" => settings.book = Some(value()?.to_owned()),
+            other => return Err(format!("Unknown option '{}'", other)),
+        }
+    }
+
+    Ok(settings)
+}
+
+fn load_dict(settings: &Settings) -> Result<Dict, Box<dyn std::error::Error>> {
+    if let Some(size) = settings.size {
+        let mut dicts = if settings.dict.ends_with(".json") {
+            DictSet::from_file(&settings.dict)?
+        } else {
+            let (dicts, report) = DictSet::from_text_files_with_report(
+                &settings.dict,
+                settings.allowed.as_ref(),
+                &settings.text,
+            )?;
+            print_normalization(settings, &report);
+            dicts
+        };
+        dicts
+            .remove(size)
+            .ok_or_else(|| format!("No answer of {} letters in dictionary", size).into())
+    } else if settings.dict.ends_with(".json") {
+        Dict::from_file(&settings.dict)
+    } else {
+        let (dict, report) = Dict::from_text_files_with_report(
+            &settings.dict,
+            settings.allowed.as_ref(),
+            &settings.text,
+        )?;
+        print_normalization(settings, &report);
+        Ok(dict)
+    }
+}
+
+/// Report words altered by normalization, in detail if verbose.
+fn print_normalization(settings: &Settings, report: &NormalizationReport) {
+    if !report.is_empty() {
+        eprintln!(
+            "Normalization changed {} word(s) and merged {} duplicate(s).",
+            report.changed.len(),
+            report.merged.len()
+        );
+    }
+    if settings.verbose {
+        for (word, normalized) in report.changed.iter() {
+            eprintln!("    changed {} -> {}", word, normalized);
+        }
+        for (word, normalized) in report.merged.iter() {
+            eprintln!("    merged {} into {}", word, normalized);
+        }
+    }
+}
+
+fn check(settings: Settings) -> Result<(), Box<dyn std::error::Error>> {
+    // Check the words the dict would be loaded from, as in load_dict
+    let report = match (settings.dict.ends_with(".json"), settings.size) {
+        (true, None) => Dict::validate_file(&settings.dict)?,
+        (true, Some(size)) => DictSet::validate_file(&settings.dict, size)?,
+        (false, None) => {
+            let (answers, allowed, _) =
+                Dict::read_text_files(&settings.dict, settings.allowed.as_ref(), &settings.text)?;
+            validate(&answers, &allowed, None)
+        }
+        (false, Some(size)) => {
+            let (answers, allowed, _) =
+                read_lists(&settings.dict, settings.allowed.as_ref(), &settings.text)?;
+            let (answers, allowed, _) = lists_of_size(answers, allowed, None, size);
+            validate(&answers, &allowed, None)
+        }
+    };
+
+    if report.is_empty() {
+        println!("No error found.");
+        Ok(())
+    } else {
+        print!("{}", report);
+        Err(format!("Found {} error(s).", report.issues.len()).into())
+    }
+}
+
+fn print_guess(game: &Game) {
+    match game.guess_next() {
+        Ok(Guess::Solution(word)) => println!("Solution: {}", word),
+        Ok(Guess::Candidate(word)) => println!("Suggested guess: {}", word),
+        Ok(Guess::Sacrifice(word)) => {
+            println!("Suggested guess: {} (cannot be the solution)", word)
+        }
+        Ok(Guess::NoSolution) => println!("No word matches the attempts, try undo."),
+        Err(err) => println!("Error: {}", err),
+    }
+}
+
+fn play(settings: Settings) -> Result<(), Box<dyn std::error::Error>> {
+    let dict = load_dict(&settings)?;
+    let book = settings
+        .book
+        .as_ref()
+        .map(OpeningBook::from_file)
+        .transpose()?;
+    let mut game = Game::new(&dict, &settings.options);
+    if let Some(book) = book.as_ref() {
+        game.set_opening_book(book)?;
+    }
+
+    println!(
+        "Loaded {} words of {} letters.\n{}",
+        dict.len(),
+        dict.word_size(),
+        HELP
+    );
+    print_guess(&game);
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        std::io::stdout().flush()?;
+        let line = match lines.next() {
+            Some(line) => line?.to_uppercase(),
+            None => return Ok(()),
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] => continue,
+            ["QUIT"] | ["EXIT"] => return Ok(()),
+            ["HELP"] => {
+                println!("{}", HELP);
+                continue;
+            }
+            ["UNDO"] => match game.undo() {
+                Some(attempt) => println!("Removed attempt {}", attempt.0),
+                None => println!("No attempt to remove"),
+            },
+            ["CANDIDATES"] => match game.candidates() {
+                Ok(candidates) => {
+                    println!("{} candidate(s):", candidates.len());
+                    candidates
+                        .iter()
+                        .for_each(|candidate| println!("    {}", candidate));
+                    continue;
+                }
+                Err(err) => println!("Error: {}", err),
+            },
+            ["GRID"] => {
+                println!("{}", to_grid(game.attempts(), &Alphabet::SUTOM));
+                continue;
+            }
+            [word, feedback] => {
+                // Attempts borrow their word from the dictionary
+                let word = match dict
+                    .answers
+                    .iter()
+                    .chain(dict.allowed.iter())
+                    .find(|w| w == word)
+                {
+                    Some(word) => word,
+                    None => {
+                        println!("Word '{}' is not in dictionary", word);
+                        continue;
+                    }
+                };
+                let attempt = Attempt::from_pattern_with(word, feedback, &settings.alphabet)
+                    .and_then(|attempt| {
+                        let solved = attempt.pattern()?.is_solved();
+                        game.add(attempt)?;
+                        Ok(solved)
+                    });
+                match attempt {
+                    Ok(true) => {
+                        println!("Solved in {} attempt(s)!", game.attempts().len());
+                        println!("{}", to_grid(game.attempts(), &Alphabet::SUTOM));
+                        return Ok(());
+                    }
+                    Ok(false) => (),
+                    Err(err) => {
+                        println!("Error: {}", err);
+                        continue;
+                    }
+                }
+            }
+            _ => {
+                println!("Unknown command, type 'help' for the list of commands");
+                continue;
+            }
+        }
+        print_guess(&game);
+    }
+}
+
+fn simulation(settings: Settings) -> Result<(), Box<dyn std::error::Error>> {
+    let dict = load_dict(&settings)?;
+    let book = settings
+        .book
+        .as_ref()
+        .map(OpeningBook::from_file)
+        .transpose()?;
+    let mut game = Game::new(&dict, &settings.options);
+    if let Some(book) = book.as_ref() {
+        game.set_opening_book(book)?;
+    }
+    let report = simulate(&mut game, settings.max_attempts)?;
+
+    if settings.verbose {
+        for result in report.results.iter() {
+            println!(
+                "{} {} {}",
+                &result.answer,
+                if result.solved { "solved" } else { "failed" },
+                result.guesses.join(",")
+            );
+        }
+    }
+
+    println!(
+        "Simulated {} games, average of {:.3} attempts.",
+        report.results.len(),
+        report.average()
+    );
+    for (index, count) in report.distribution().iter().enumerate() {
+        println!("    {} attempt(s): {}", index + 1, count);
+    }
+    let failures = report.failures();
+    println!("    failed: {}", failures.len());
+    failures
+        .iter()
+        .for_each(|failure| println!("        {}", failure.answer));
+    Ok(())
+}
+
+fn tree(settings: Settings) -> Result<(), Box<dyn std::error::Error>> {
+    let dict = load_dict(&settings)?;
+    let tree = if settings.optimal {
+        DecisionTree::optimal(&dict, &FeedbackMatrix::of_dict(&dict)?)?
+    } else {
+        DecisionTree::of_game(&mut Game::new(&dict, &settings.options))?
+    };
+
+    if settings.dot {
+        print!("{}", tree.to_dot(&settings.alphabet));
+    } else {
+        println!("{}", tree.to_json(&settings.alphabet));
+    }
+    Ok(())
+}
+
+fn book(settings: Settings) -> Result<(), Box<dyn std::error::Error>> {
+    let dict = load_dict(&settings)?;
+    let book = OpeningBook::of_game(&mut Game::new(&dict, &settings.options), true)?;
+
+    println!("{}", serde_json::to_string(&book)?);
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.split_first() {
+        Some((command, args))
+            if ["play", "simulate", "tree", "book", "check"].contains(&command.as_str()) =>
+        {
+            match parse_settings(args) {
+                Ok(settings) if command == "play" => play(settings),
+                Ok(settings) if command == "simulate" => simulation(settings),
+                Ok(settings) if command == "tree" => tree(settings),
+                Ok(settings) if command == "book" => book(settings),
+                Ok(settings) => check(settings),
+                Err(err) => Err(format!("{}\n\n{}", err, USAGE).into()),
+            }
+        }
+        _ => Err(USAGE.to_string().into()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::attempt::Attempt;
+use crate::errors::Error;
+use crate::game::{Game, Guess};
+use crate::options::Options;
+use crate::pattern::Pattern;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// Precomputed guesses of the first turns of a game, which only depend on
+/// the dictionnary and the options of the game.
+///
+/// A book is not looked for by games: it must be loaded (e.g. with
+/// `from_file`) and given to each game with `Game::set_opening_book`, which
+/// then consults it automatically in `guess_next`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpeningBook {
+    /// Fingerprint of the dictionnary of the game
+    pub fingerprint: u64,
+    /// Options of the game
+    pub options: Options,
+    /// Guess of the first turn
+    pub first: Guess,
+    /// Guess of the second turn for each pattern the first guess may
+    /// produce (except the solved pattern), sorted by pattern
+    pub second: Vec<(Pattern, Guess)>,
+}
+
+impl OpeningBook {
+    /// Compute the guesses of a game for its first turn, and optionally for
+    /// its second turn.
+    ///
+    /// Attempts of the game are cleared before computing guesses, and
+    /// restored when done. Games with a custom strategy are rejected.
+    pub fn of_game<'a>(game: &mut Game<'a>, second_turn: bool) -> Result<Self, Error> {
+        if game.has_custom_strategy() {
+            return Err(Error::CustomStrategy);
+        }
+        let mut attempts: Vec<Attempt<'a>> = Vec::with_capacity(game.attempts().len());
+        while let Some(attempt) = game.undo() {
+            attempts.push(attempt);
+        }
+
+        let book = Self::of_new_game(game, second_turn);
+        game.truncate(0);
+        for attempt in attempts.into_iter().rev() {
+            game.add(attempt)?;
+        }
+        book
+    }
+
+    /// Compute the guesses of a game without attempts.
+    fn of_new_game(game: &mut Game, second_turn: bool) -> Result<Self, Error> {
+        let dict = game.dict();
+        let first = game.guess_next()?;
+
+        let mut second = Vec::new();
+        let word = match &first {
+            Guess::Candidate(word) | Guess::Sacrifice(word) if second_turn => dict
+                .answers
+                .iter()
+                .chain(dict.allowed.iter())
+                .find(|w| *w == word),
+            _ => None,
+        };
+        if let Some(word) = word {
+            let mut patterns = dict
+                .answers
+                .iter()
+                .map(|answer| Attempt::from_answer(word, answer)?.pattern())
+                .collect::<Result<Vec<Pattern>, Error>>()?;
+            patterns.sort_unstable();
+            patterns.dedup();
+
+            for pattern in patterns.into_iter().filter(|pattern| !pattern.is_solved()) {
+                game.add(Attempt(word, pattern.states()))?;
+                let guess = game.guess_next();
+                game.truncate(0);
+                second.push((pattern, guess?));
+            }
+        }
+
+        Ok(OpeningBook {
+            fingerprint: dict.fingerprint(),
+            options: game.options().clone(),
+            first,
+            second,
+        })
+    }
+
+    /// Return the guess following a sequence of attempts, if it is in the book.
+    pub fn lookup(&self, attempts: &[Attempt]) -> Option<&Guess> {
+        match attempts {
+            [] => Some(&self.first),
+            [attempt] => match &self.first {
+                Guess::Candidate(word) | Guess::Sacrifice(word) if word == attempt.0 => {
+                    let pattern = attempt.pattern().ok()?;
+                    self.second
+                        .binary_search_by(|(branch, _)| branch.cmp(&pattern))
+                        .ok()
+                        .map(|index| &self.second[index].1)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+}
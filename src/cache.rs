@@ -0,0 +1,87 @@
+use crate::answers::{Answers, DictAnswers};
+use crate::errors::Error;
+use crate::options::Options;
+use crate::tests::{Test, TestSuite};
+use crate::Dict;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// Dictionnary of a game along with the tests and answers computed for it,
+/// which can be stored in a compact binary file to create games instantly.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct GameCache {
+    /// Fingerprint of the dictionnary
+    pub fingerprint: u64,
+    /// Options the tests were generated with
+    pub options: Options,
+    pub dict: Dict,
+    pub tests: TestSuite,
+    pub dict_answers: DictAnswers,
+}
+
+impl GameCache {
+    /// Version of the binary format of cache files, to be increased whenever
+    /// the layout of the cache or of its content changes
+    pub const VERSION: u32 = 1;
+
+    /// Compute the tests of a dict and their answers for each word.
+    pub fn new(dict: Dict, options: &Options) -> Self {
+        let tests = Test::for_dict(&dict, options);
+        let dict_answers = Answers::of_dict(&dict, &tests);
+        GameCache {
+            fingerprint: dict.fingerprint(),
+            options: options.clone(),
+            dict,
+            tests,
+            dict_answers,
+        }
+    }
+
+    /// Check whether the cache was built for the given dict.
+    pub fn is_of_dict(&self, dict: &Dict) -> bool {
+        self.fingerprint == dict.fingerprint()
+    }
+
+    /// Load a cache, checking its format version, the fingerprint of its dict
+    /// and that its tests and answers match the dict, so as to detect
+    /// corrupted files.
+    ///
+    /// Whether the cache was built for an expected dict is left to the caller,
+    /// e.g. with `is_of_dict`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+
+        let version: u32 = bincode::deserialize_from(&mut reader)?;
+        if version != Self::VERSION {
+            return Err(Box::new(Error::UnsupportedCacheVersion(
+                Self::VERSION,
+                version,
+            )));
+        }
+
+        let cache: GameCache = bincode::deserialize_from(reader)?;
+        let fingerprint = cache.dict.fingerprint();
+        if cache.fingerprint != fingerprint {
+            return Err(Box::new(Error::CorruptedCache(
+                fingerprint,
+                cache.fingerprint,
+            )));
+        }
+        if !cache.dict_answers.is_of(&cache.dict, &cache.tests) {
+            return Err(Box::new(Error::InconsistentCache));
+        }
+        Ok(cache)
+    }
+
+    /// Store the cache, preceded by the version of its format.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+
+        bincode::serialize_into(&mut writer, &Self::VERSION)?;
+        Ok(bincode::serialize_into(writer, self)?)
+    }
+}
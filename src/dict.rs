@@ -1,13 +1,128 @@
-use crate::errors::DictError;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use crate::errors::{DictError, DictIssue, DictReport};
+use crate::normalize::{normalize, NormalizationReport};
+use rayon::iter::{IntoParallelRefIterator, ParallelExtend, ParallelIterator};
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 
 pub type WordList = Vec<String>;
 
+/// Lists of words of a dictionnary.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ListKind {
+    Answers,
+    Allowed,
+}
+
+impl std::fmt::Display for ListKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Answers => write!(f, "answers"),
+            Self::Allowed => write!(f, "allowed"),
+        }
+    }
+}
+
+/// Options of plain-text word lists, made of one word per line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextOptions {
+    /// Lines starting with this prefix (after trimming) are ignored
+    pub comment_prefix: String,
+    /// Skip lines which are not words of the answers size, made of ASCII
+    /// letters, instead of failing when building the dict
+    pub skip_invalid: bool,
+    /// Strip diacritics and expand ligatures of words, merging words which
+    /// become duplicates
+    pub normalize: bool,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        TextOptions {
+            comment_prefix: "#".to_string(),
+            skip_invalid: false,
+            normalize: false,
+        }
+    }
+}
+
+/// Read a plain-text word list: lines are trimmed and upper-cased (and
+/// normalized if required in options), blank lines and comments are ignored.
+///
+/// Lines which are not made of ASCII letters are skipped if required in
+/// options, and kept as is otherwise.
+pub fn read_words<R: BufRead>(reader: R, options: &TextOptions) -> std::io::Result<WordList> {
+    read_words_into(
+        reader,
+        options,
+        &mut HashSet::new(),
+        &mut NormalizationReport::default(),
+    )
+}
+
+/// Read plain-text lists of answers and, optionally, of allowed words, as
+/// dicts are loaded from them: when normalizing, allowed words which become
+/// duplicates of answers are merged into them, and words altered by
+/// normalization are reported.
+pub fn read_lists<P: AsRef<Path>>(
+    answers: P,
+    allowed: Option<P>,
+    options: &TextOptions,
+) -> std::io::Result<(WordList, WordList, NormalizationReport)> {
+    let mut seen = HashSet::new();
+    let mut report = NormalizationReport::default();
+    let mut read = |path: P| -> std::io::Result<WordList> {
+        let reader = BufReader::new(File::open(path)?);
+        read_words_into(reader, options, &mut seen, &mut report)
+    };
+    let answers = read(answers)?;
+    let allowed = match allowed {
+        Some(path) => read(path)?,
+        None => WordList::new(),
+    };
+    Ok((answers, allowed, report))
+}
+
+/// Read a plain-text word list, skipping normalized words already seen and
+/// reporting words altered by normalization.
+fn read_words_into<R: BufRead>(
+    reader: R,
+    options: &TextOptions,
+    seen: &mut HashSet<String>,
+    report: &mut NormalizationReport,
+) -> std::io::Result<WordList> {
+    let mut words = WordList::new();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(&options.comment_prefix) {
+            continue;
+        }
+        let word = if options.normalize {
+            normalize(line)
+        } else {
+            line.to_uppercase()
+        };
+        if options.skip_invalid && !word.chars().all(|c| c.is_ascii_uppercase()) {
+            continue;
+        }
+        if options.normalize {
+            if seen.contains(&word) {
+                report.merged.push((line.to_owned(), word));
+                continue;
+            }
+            seen.insert(word.clone());
+            if word != line.to_uppercase() {
+                report.changed.push((line.to_owned(), word.clone()));
+            }
+        }
+        words.push(word);
+    }
+    Ok(words)
+}
+
 /// Dictionnaries contain the list of words allowed for a given game.
 #[derive(Debug, PartialEq)]
 pub struct Dict {
@@ -17,13 +132,40 @@ pub struct Dict {
     pub allowed: WordList,
     /// Size of words
     size: usize,
+    /// Relative frequency of each answer, if not equally likely
+    weights: Option<Vec<f64>>,
+    /// Every word (answers and allowed), for constant time lookups
+    index: HashSet<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct DictRepr {
-    answers: WordList,
+#[derive(Debug, Deserialize)]
+pub(crate) struct DictRepr {
+    pub(crate) answers: WordList,
+    #[serde(default)]
+    pub(crate) allowed: WordList,
     #[serde(default)]
-    allowed: WordList,
+    pub(crate) weights: Option<Vec<f64>>,
+}
+
+impl Serialize for DictRepr {
+    /// Leave out missing weights in human-readable formats (e.g. JSON), but
+    /// not in binary formats which cannot skip fields (e.g. bincode).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_weights = self.weights.is_none() && serializer.is_human_readable();
+        let mut state =
+            serializer.serialize_struct("DictRepr", if skip_weights { 2 } else { 3 })?;
+        state.serialize_field("answers", &self.answers)?;
+        state.serialize_field("allowed", &self.allowed)?;
+        if skip_weights {
+            state.skip_field("weights")?;
+        } else {
+            state.serialize_field("weights", &self.weights)?;
+        }
+        state.end()
+    }
 }
 
 impl Serialize for Dict {
@@ -34,6 +176,7 @@ impl Serialize for Dict {
         let dict_repr = DictRepr {
             answers: self.answers.clone(),
             allowed: self.allowed.clone(),
+            weights: self.weights.clone(),
         };
         dict_repr.serialize(serializer)
     }
@@ -45,22 +188,46 @@ impl<'de> Deserialize<'de> for Dict {
         D: Deserializer<'de>,
     {
         let dict_repr = DictRepr::deserialize(deserializer)?;
-        Dict::new(dict_repr.answers, dict_repr.allowed)
-            .map_err(|err| D::Error::custom(format!("{}", err)))
+        match dict_repr.weights {
+            None => Dict::new(dict_repr.answers, dict_repr.allowed),
+            Some(weights) => Dict::new_with_weights(dict_repr.answers, dict_repr.allowed, weights),
+        }
+        .map_err(|err| D::Error::custom(format!("{}", err)))
     }
 }
 
 // Implement constructor for Dict
 impl Dict {
     pub fn new(answers: WordList, allowed: WordList) -> Result<Self, DictError> {
+        Self::build(answers, allowed, None)
+    }
+
+    /// Create a dict whose answers are not equally likely, given the
+    /// relative frequency of each answer.
+    pub fn new_with_weights(
+        answers: WordList,
+        allowed: WordList,
+        weights: Vec<f64>,
+    ) -> Result<Self, DictError> {
+        Self::build(answers, allowed, Some(weights))
+    }
+
+    fn build(
+        answers: WordList,
+        allowed: WordList,
+        weights: Option<Vec<f64>>,
+    ) -> Result<Self, DictError> {
         if answers.is_empty() {
             return Err(DictError::MissingAnswers);
         }
-        let size = answers.get(0).unwrap().len();
+        let size = answers.first().unwrap().chars().count();
+        let index = answers.iter().chain(allowed.iter()).cloned().collect();
         let dict = Dict {
             answers,
             allowed,
             size,
+            weights,
+            index,
         };
         match dict.check() {
             None => Ok(dict),
@@ -86,7 +253,7 @@ impl std::iter::IntoIterator for Dict {
     type IntoIter = std::iter::Chain<std::vec::IntoIter<String>, std::vec::IntoIter<String>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.answers.into_iter().chain(self.allowed.into_iter())
+        self.answers.into_iter().chain(self.allowed)
     }
 }
 
@@ -103,6 +270,51 @@ impl Dict {
         self.size
     }
 
+    /// Relative frequency of each answer, if answers are not equally likely.
+    pub fn weights(&self) -> Option<&[f64]> {
+        self.weights.as_deref()
+    }
+
+    /// Relative frequency of the answer at given index (1 if answers are
+    /// equally likely).
+    pub fn weight(&self, index: usize) -> f64 {
+        self.weights.as_ref().map_or(1., |weights| weights[index])
+    }
+
+    /// Compute a fingerprint of the dictionnary content.
+    ///
+    /// The fingerprint (64-bit FNV-1a hash of answers then allowed words, then
+    /// weights if any) is stable across builds so that it can be persisted.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        self.answers
+            .iter()
+            .flat_map(|word| word.bytes().chain(std::iter::once(b'\n')))
+            .chain(std::iter::once(b'\0'))
+            .chain(
+                self.allowed
+                    .iter()
+                    .flat_map(|word| word.bytes().chain(std::iter::once(b'\n'))),
+            )
+            .chain(self.weights.iter().flat_map(|weights| {
+                std::iter::once(b'\0').chain(
+                    weights
+                        .iter()
+                        .flat_map(|weight| weight.to_bits().to_le_bytes()),
+                )
+            }))
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Check whether a word is either an answer or an allowed word
+    pub fn contains(&self, word: &str) -> bool {
+        self.index.contains(word)
+    }
+
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
@@ -114,65 +326,167 @@ impl Dict {
             None => Ok(dict),
         }
     }
+
+    /// Check a JSON dictionnary file, and report every error found in it.
+    pub fn validate_file<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<DictReport, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        let dict_repr: DictRepr = serde_json::from_reader(reader)?;
+        Ok(validate(
+            &dict_repr.answers,
+            &dict_repr.allowed,
+            dict_repr.weights.as_deref(),
+        ))
+    }
+
+    /// Load a dict from plain-text word lists of answers and, optionally, of
+    /// allowed words.
+    ///
+    /// When skipping invalid lines, words whose size differs from the first
+    /// answer are skipped too.
+    pub fn from_text_files<P: AsRef<Path>>(
+        answers: P,
+        allowed: Option<P>,
+        options: &TextOptions,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_text_files_with_report(answers, allowed, options).map(|(dict, _)| dict)
+    }
+
+    /// Load a dict from plain-text word lists, and report the words altered
+    /// by normalization (if required in options).
+    ///
+    /// Allowed words which become duplicates of answers are merged into them.
+    pub fn from_text_files_with_report<P: AsRef<Path>>(
+        answers: P,
+        allowed: Option<P>,
+        options: &TextOptions,
+    ) -> Result<(Self, NormalizationReport), Box<dyn std::error::Error>> {
+        let (answers, allowed, report) = Self::read_text_files(answers, allowed, options)?;
+        Ok((Dict::new(answers, allowed)?, report))
+    }
+
+    /// Read the words a dict is loaded from by `from_text_files`, without
+    /// checking them (see validate).
+    pub fn read_text_files<P: AsRef<Path>>(
+        answers: P,
+        allowed: Option<P>,
+        options: &TextOptions,
+    ) -> std::io::Result<(WordList, WordList, NormalizationReport)> {
+        let (mut answers, mut allowed, report) = read_lists(answers, allowed, options)?;
+
+        if options.skip_invalid {
+            if let Some(size) = answers.first().map(|word| word.chars().count()) {
+                answers.retain(|word| word.chars().count() == size);
+                allowed.retain(|word| word.chars().count() == size);
+            }
+        }
+        Ok((answers, allowed, report))
+    }
 }
 
 impl Dict {
-    /// Check the dictionnary consistency
-    ///
-    /// Performs following checks:
-    /// * All words have the same size
-    /// * No duplicate exist in the word list
-    /// * Only allowed characters (ASCII uppercase) are used
+    /// Check the dictionnary consistency, returning the first error found
+    /// (see validate).
     fn check(&self) -> Option<DictError> {
-        self.check_size()
-            .or_else(|| self.check_duplicate().or_else(|| self.check_characters()))
+        validate(&self.answers, &self.allowed, self.weights.as_deref())
+            .issues
+            .into_iter()
+            .next()
+            .map(|issue| issue.error)
     }
+}
 
-    /// Check that all words share the same size
-    fn check_size(&self) -> Option<DictError> {
-        let size = self.size;
-        let invalid_word = self
-            .par_iter()
-            .find_any(|&word| word.chars().count() != size);
-
-        invalid_word
-            .map(|word| DictError::InconsistentSize(size, word.chars().count(), word.to_owned()))
+/// Check the word lists (and answer weights) of a dictionnary, and report
+/// every error found.
+///
+/// Performs following checks:
+/// * All words have the same size as the first answer
+/// * No duplicate exist in the word list (each occurrence after the first
+///   one is reported)
+/// * Only allowed characters (ASCII uppercase) are used
+/// * Weights, if any, are given for each answer and are positive
+///
+/// Issues are ordered by location of the incriminated word (answers, then
+/// allowed words), issues unrelated to a single word coming first.
+pub fn validate(answers: &[String], allowed: &[String], weights: Option<&[f64]>) -> DictReport {
+    let mut issues = Vec::new();
+    let size = answers.first().map(|word| word.chars().count());
+    if size.is_none() {
+        issues.push(DictIssue {
+            location: None,
+            error: DictError::MissingAnswers,
+        });
     }
 
-    /// Check list contains no duplicate
-    fn check_duplicate(&self) -> Option<DictError> {
-        let count_map = self
-            .par_iter()
-            .fold(HashMap::new, |mut acc, word| {
-                let count = acc.entry(word).or_insert(0);
-                *count += 1;
-                acc
-            })
-            .reduce(HashMap::new, |m1, m2| {
-                m2.iter().fold(m1, |mut acc, (&word, &count_m2)| {
-                    let count = acc.entry(word).or_insert(0);
-                    *count += count_m2;
-                    acc
-                })
-            });
+    let words: Vec<(ListKind, usize, &String)> = answers
+        .iter()
+        .enumerate()
+        .map(|(index, word)| (ListKind::Answers, index, word))
+        .chain(
+            allowed
+                .iter()
+                .enumerate()
+                .map(|(index, word)| (ListKind::Allowed, index, word)),
+        )
+        .collect();
 
-        let non_unique_word = count_map.par_iter().find_any(|(_, &count)| count != 1);
+    // Check size and characters of each word
+    issues.par_extend(words.par_iter().flat_map_iter(|&(list, index, word)| {
+        let location = Some((list, index));
+        let count = word.chars().count();
+        let size_issue = size.filter(|size| *size != count).map(|size| DictIssue {
+            location,
+            error: DictError::InconsistentSize(size, count, word.to_owned()),
+        });
+        let character_issue = word
+            .chars()
+            .find(|character| !character.is_ascii_uppercase())
+            .map(|character| DictIssue {
+                location,
+                error: DictError::UnauthorizedCharacter(character, word.to_owned()),
+            });
+        size_issue.into_iter().chain(character_issue)
+    }));
 
-        non_unique_word.map(|(&word, &count)| DictError::DuplicateWord(count, word.to_owned()))
+    // Check duplicates
+    let mut occurrences: HashMap<&str, Vec<(ListKind, usize)>> = HashMap::new();
+    for &(list, index, word) in words.iter() {
+        occurrences.entry(word).or_default().push((list, index));
+    }
+    for (word, locations) in occurrences
+        .iter()
+        .filter(|(_, locations)| locations.len() > 1)
+    {
+        issues.extend(locations.iter().skip(1).map(|&location| DictIssue {
+            location: Some(location),
+            error: DictError::DuplicateWord(locations.len(), word.to_string()),
+        }));
     }
 
-    /// Check all characters are allowed
-    fn check_characters(&self) -> Option<DictError> {
-        let invalid_word = self
-            .par_iter()
-            .find_any(|&word| !word.chars().all(|character| character.is_ascii_uppercase()));
-
-        invalid_word.map(|word| {
-            let bad_char = word
-                .chars()
-                .find(|character| !character.is_ascii_uppercase())
-                .unwrap();
-            DictError::UnauthorizedCharacter(bad_char, word.to_owned())
-        })
+    // Check weights
+    if let Some(weights) = weights {
+        if weights.len() != answers.len() {
+            issues.push(DictIssue {
+                location: None,
+                error: DictError::InconsistentWeights(answers.len(), weights.len()),
+            });
+        }
+        issues.extend(
+            weights
+                .iter()
+                .zip(answers.iter())
+                .enumerate()
+                .filter(|(_, (weight, _))| !weight.is_finite() || **weight <= 0.)
+                .map(|(index, (weight, word))| DictIssue {
+                    location: Some((ListKind::Answers, index)),
+                    error: DictError::InvalidWeight(*weight, word.to_owned()),
+                }),
+        );
     }
+
+    issues.sort_by_key(|issue| issue.location);
+    DictReport { issues }
 }
@@ -0,0 +1,236 @@
+use crate::dict::{read_lists, validate, DictRepr, TextOptions, WordList};
+use crate::errors::{DictError, DictReport};
+use crate::normalize::NormalizationReport;
+use crate::Dict;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// Keep the words of a given size of lists of mixed sizes, as they are split
+/// into dicts, along with the weights of kept answers.
+///
+/// Weights are kept as is if they do not match answers, so that validation
+/// still reports it.
+pub fn lists_of_size(
+    answers: WordList,
+    allowed: WordList,
+    weights: Option<Vec<f64>>,
+    size: usize,
+) -> (WordList, WordList, Option<Vec<f64>>) {
+    let is_of_size = |word: &String| word.chars().count() == size;
+    let weights = match weights {
+        Some(weights) if weights.len() == answers.len() => Some(
+            answers
+                .iter()
+                .zip(weights)
+                .filter(|(word, _)| is_of_size(word))
+                .map(|(_, weight)| weight)
+                .collect(),
+        ),
+        weights => weights,
+    };
+    (
+        answers.into_iter().filter(is_of_size).collect(),
+        allowed.into_iter().filter(is_of_size).collect(),
+        weights,
+    )
+}
+
+/// A DictSet contains a dictionnary for each size of words of a game (e.g.
+/// words of 6 to 10 letters in Sutom).
+#[derive(Debug, PartialEq)]
+pub struct DictSet {
+    dicts: BTreeMap<usize, Dict>,
+}
+
+impl Serialize for DictSet {
+    /// Serialize dicts as a single dict of words of mixed sizes, sorted by size.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let weighted = self.dicts.values().any(|dict| dict.weights().is_some());
+        let dict_repr = DictRepr {
+            answers: self
+                .dicts
+                .values()
+                .flat_map(|dict| dict.answers.iter().cloned())
+                .collect(),
+            allowed: self
+                .dicts
+                .values()
+                .flat_map(|dict| dict.allowed.iter().cloned())
+                .collect(),
+            weights: weighted.then(|| {
+                self.dicts
+                    .values()
+                    .flat_map(|dict| (0..dict.answers.len()).map(|index| dict.weight(index)))
+                    .collect()
+            }),
+        };
+        dict_repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DictSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dict_repr = DictRepr::deserialize(deserializer)?;
+        match dict_repr.weights {
+            None => DictSet::new(dict_repr.answers, dict_repr.allowed),
+            Some(weights) => {
+                DictSet::new_with_weights(dict_repr.answers, dict_repr.allowed, weights)
+            }
+        }
+        .map_err(|err| D::Error::custom(format!("{}", err)))
+    }
+}
+
+impl DictSet {
+    /// Split lists of answers and allowed words of mixed sizes into a dict
+    /// per size.
+    ///
+    /// Allowed words of a size without any answer are left out.
+    pub fn new(answers: WordList, allowed: WordList) -> Result<Self, DictError> {
+        Self::build(answers, allowed, None)
+    }
+
+    /// Split lists of answers (with their relative frequency) and allowed
+    /// words of mixed sizes into a dict per size.
+    pub fn new_with_weights(
+        answers: WordList,
+        allowed: WordList,
+        weights: Vec<f64>,
+    ) -> Result<Self, DictError> {
+        if weights.len() != answers.len() {
+            return Err(DictError::InconsistentWeights(answers.len(), weights.len()));
+        }
+        Self::build(answers, allowed, Some(weights))
+    }
+
+    fn build(
+        answers: WordList,
+        allowed: WordList,
+        weights: Option<Vec<f64>>,
+    ) -> Result<Self, DictError> {
+        if answers.is_empty() {
+            return Err(DictError::MissingAnswers);
+        }
+
+        let mut lists: BTreeMap<usize, (WordList, WordList, Vec<f64>)> = BTreeMap::new();
+        for (index, word) in answers.into_iter().enumerate() {
+            let list = lists.entry(word.chars().count()).or_default();
+            list.0.push(word);
+            if let Some(weights) = weights.as_ref() {
+                list.2.push(weights[index]);
+            }
+        }
+        for word in allowed.into_iter() {
+            if let Some(list) = lists.get_mut(&word.chars().count()) {
+                list.1.push(word);
+            }
+        }
+
+        let dicts = lists
+            .into_iter()
+            .map(|(size, (answers, allowed, list_weights))| {
+                let dict = match weights {
+                    None => Dict::new(answers, allowed),
+                    Some(_) => Dict::new_with_weights(answers, allowed, list_weights),
+                };
+                dict.map(|dict| (size, dict))
+            })
+            .collect::<Result<BTreeMap<usize, Dict>, DictError>>()?;
+
+        Ok(DictSet { dicts })
+    }
+
+    /// Return the dict of words of a given size, if any.
+    pub fn get(&self, size: usize) -> Option<&Dict> {
+        self.dicts.get(&size)
+    }
+
+    /// Take the dict of words of a given size out of the set, if any.
+    pub fn remove(&mut self, size: usize) -> Option<Dict> {
+        self.dicts.remove(&size)
+    }
+
+    /// Sizes of words having a dict, in increasing order.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.dicts.keys().copied()
+    }
+
+    /// Iterate over dicts by increasing size of words.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Dict)> {
+        self.dicts.iter().map(|(size, dict)| (*size, dict))
+    }
+
+    /// Number of dicts, i.e. of distinct sizes of words.
+    pub fn len(&self) -> usize {
+        self.dicts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dicts.is_empty()
+    }
+
+    /// Load dicts from plain-text word lists of answers and, optionally, of
+    /// allowed words, of mixed sizes.
+    pub fn from_text_files<P: AsRef<Path>>(
+        answers: P,
+        allowed: Option<P>,
+        options: &TextOptions,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_text_files_with_report(answers, allowed, options).map(|(dicts, _)| dicts)
+    }
+
+    /// Load dicts from plain-text word lists of mixed sizes, and report the
+    /// words altered by normalization (if required in options).
+    pub fn from_text_files_with_report<P: AsRef<Path>>(
+        answers: P,
+        allowed: Option<P>,
+        options: &TextOptions,
+    ) -> Result<(Self, NormalizationReport), Box<dyn std::error::Error>> {
+        let (answers, allowed, report) = read_lists(answers, allowed, options)?;
+        Ok((DictSet::new(answers, allowed)?, report))
+    }
+
+    /// Check the words of a given size of a JSON dictionnary file of mixed
+    /// sizes, and report every error found in them.
+    ///
+    /// Locations of errors are indices in the lists of words of this size.
+    pub fn validate_file<P: AsRef<Path>>(
+        path: P,
+        size: usize,
+    ) -> Result<DictReport, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        let dict_repr: DictRepr = serde_json::from_reader(reader)?;
+        let (answers, allowed, weights) = lists_of_size(
+            dict_repr.answers,
+            dict_repr.allowed,
+            dict_repr.weights,
+            size,
+        );
+        Ok(validate(&answers, &allowed, weights.as_deref()))
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+}
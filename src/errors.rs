@@ -1,30 +1,141 @@
 use crate::answers::Answer;
+use crate::dict::ListKind;
+use crate::knowledge::Hint;
+use crate::pattern::Pattern;
 use crate::tests::Test;
 
 #[derive(Debug)]
 pub enum Error {
+    CorruptedCache(u64, u64),  // dict fingerprint, stored fingerprint
+    CustomStrategy,            // Game ranks guesses with a custom strategy
+    IgnoredHint(Hint, String), // ignored hint, incriminated word
     IncompatibleAnswers(Answer, Answer),
-    InvalidSize(usize, usize, String), // expected size, found size, incriminated word
-    UnexpectedTest(Test, String),      // Invalid test, incriminated word
+    InconsistentAttempt(String),                // incriminated word
+    InconsistentCache,                          // Tests or answers computed for another dict
+    InvalidAttemptIndex(usize, usize),          // index, number of attempts
+    InvalidGridSize(usize, usize),              // expected rows, found rows
+    InvalidPatternSize(usize, usize, String),   // expected size, found size, typed pattern
+    InvalidRecordSize(usize, usize),            // dict word size, record word size
+    InvalidRevealedLetter(usize, char),         // position, revealed letter
+    InvalidSize(usize, usize, String),          // expected size, found size, incriminated word
+    MissingRevealedLetter(usize, char, String), // position, revealed letter, incriminated word
+    NoProgress(usize),                          // number of answers left to tell apart
+    UnexpectedTest(Test, String),               // Invalid test, incriminated word
+    PatternTooLong(usize),                      // found size
+    UnknownSymbol(char, String),                // incriminated symbol, typed pattern
+    UnknownWord(String),                        // incriminated word
+    UnrelatedDecisionTree,                      // Decision tree built for another dict
+    UnrelatedFeedbackMatrix,                    // Feedback matrix computed for another dict
+    UnrelatedOpeningBook,                       // Opening book computed for another dict or options
+    UnrelatedRecord(u64, u64),                  // dict fingerprint, record fingerprint
+    UnsupportedCacheVersion(u32, u32),          // supported version, found version
 }
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::CorruptedCache(expected, found) => write!(
+                f,
+                "Cache is corrupted: dictionary does not match ({:016x} != {:016x})",
+                &found, &expected
+            ),
+            Self::CustomStrategy => {
+                write!(f, "Game ranks guesses with a custom strategy.")
+            }
+            Self::IgnoredHint(hint, word) => {
+                write!(f, "Word '{}' does not reuse hint: {}", &word, &hint)
+            }
             Self::IncompatibleAnswers(lhs, rhs) => write!(
                 f,
                 "Attempting to merge {:?} with {:?}: conflicting operation.",
                 *lhs, *rhs,
             ),
+            Self::InconsistentAttempt(word) => write!(
+                f,
+                "States of attempt '{}' contradict previous attempts",
+                &word
+            ),
+            Self::InconsistentCache => {
+                write!(f, "Cache tests and answers do not match its dictionary.")
+            }
+            Self::InvalidAttemptIndex(index, count) => write!(
+                f,
+                "No attempt at index {} (game has {} attempts)",
+                &index, &count
+            ),
+            Self::InvalidGridSize(expected, found) => write!(
+                f,
+                "Grid contains {} rows but {} words were given",
+                &found, &expected
+            ),
+            Self::InvalidPatternSize(expected, found, pattern) => write!(
+                f,
+                "Size of pattern '{}' ({}) differs from size of word ({})",
+                &pattern, &found, &expected
+            ),
+            Self::InvalidRecordSize(expected, found) => write!(
+                f,
+                "Record was made with words of size {} (expected {})",
+                &found, &expected
+            ),
+            Self::InvalidRevealedLetter(position, letter) => write!(
+                f,
+                "Revealed letter '{}' at position {} is not an uppercase letter of a word",
+                &letter, &position
+            ),
             Self::InvalidSize(expected, found, word) => write!(
                 f,
                 "Size of '{}' ({}) differs from expectation ({})",
                 &word, &expected, &found
             ),
+            Self::MissingRevealedLetter(position, letter, word) => write!(
+                f,
+                "Word '{}' does not contain revealed letter '{}' at position {}",
+                &word, &letter, &position
+            ),
+            Self::NoProgress(count) => write!(
+                f,
+                "Guess does not tell apart the {} remaining answers",
+                &count
+            ),
             Self::UnexpectedTest(test, word) => {
                 write!(f, "Test {:?} incompatible with word '{}'", test, word)
             }
+            Self::PatternTooLong(size) => write!(
+                f,
+                "Size {} exceeds the maximum size of a pattern ({})",
+                &size,
+                Pattern::MAX_SIZE
+            ),
+            Self::UnknownSymbol(symbol, pattern) => {
+                write!(
+                    f,
+                    "Pattern '{}' contains unknown symbol '{}'",
+                    &pattern, &symbol
+                )
+            }
+            Self::UnknownWord(word) => write!(f, "Word '{}' is not in dictionary", &word),
+            Self::UnrelatedDecisionTree => {
+                write!(f, "Decision tree was not built for this dictionary.")
+            }
+            Self::UnrelatedFeedbackMatrix => {
+                write!(f, "Feedback matrix was not computed for this dictionary.")
+            }
+            Self::UnrelatedOpeningBook => write!(
+                f,
+                "Opening book was not computed for this dictionary and options."
+            ),
+            Self::UnrelatedRecord(expected, found) => write!(
+                f,
+                "Record was made with another dictionary ({:016x} != {:016x})",
+                &found, &expected
+            ),
+            Self::UnsupportedCacheVersion(expected, found) => write!(
+                f,
+                "Cache format version {} is not supported (expected {})",
+                &found, &expected
+            ),
         }
     }
 }
@@ -33,6 +144,8 @@ impl std::fmt::Display for Error {
 pub enum DictError {
     InconsistentSize(usize, usize, String), // expected size, found size, incriminated word
     DuplicateWord(usize, String),           // incriminated word count and value
+    InconsistentWeights(usize, usize),      // answers count, weights count
+    InvalidWeight(f64, String),             // incriminated weight and word
     MissingAnswers,                         // Answer list is empty
     UnauthorizedCharacter(char, String),    // incriminated character and word
 }
@@ -50,6 +163,14 @@ impl std::fmt::Display for DictError {
             Self::DuplicateWord(count, word) => {
                 write!(f, "Word '{}' found {} times.", &word, &count)
             }
+            Self::InconsistentWeights(expected, found) => {
+                write!(f, "Found {} weights for {} answers", &found, &expected)
+            }
+            Self::InvalidWeight(weight, word) => write!(
+                f,
+                "Weight of '{}' is not a positive number ({})",
+                &word, &weight
+            ),
             Self::MissingAnswers => {
                 write!(f, "List of answers is empty.")
             }
@@ -61,3 +182,42 @@ impl std::fmt::Display for DictError {
         }
     }
 }
+
+/// An error found in a dictionnary, with the location of the incriminated word.
+#[derive(Debug)]
+pub struct DictIssue {
+    /// List and index of the incriminated word, if any
+    pub location: Option<(ListKind, usize)>,
+    pub error: DictError,
+}
+
+impl std::fmt::Display for DictIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.location {
+            Some((list, index)) => write!(f, "{}[{}]: {}", &list, &index, &self.error),
+            None => write!(f, "{}", &self.error),
+        }
+    }
+}
+
+/// All the errors found in a dictionnary.
+#[derive(Debug, Default)]
+pub struct DictReport {
+    pub issues: Vec<DictIssue>,
+}
+
+impl DictReport {
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl std::error::Error for DictReport {}
+
+impl std::fmt::Display for DictReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.issues
+            .iter()
+            .try_for_each(|issue| writeln!(f, "{}", issue))
+    }
+}
@@ -0,0 +1,106 @@
+use crate::errors::Error;
+use crate::pattern::Pattern;
+use crate::Dict;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::collections::HashMap;
+
+/// Compute the pattern of a word attempted against an answer.
+///
+/// Both words are expected to be of the same size (at most Pattern::MAX_SIZE)
+/// and made of ASCII uppercase characters, as guaranteed by Dict.
+fn feedback(attempt: &[u8], answer: &[u8]) -> Pattern {
+    let mut states = [0u8; Pattern::MAX_SIZE];
+    let mut acc = [0u8; 26];
+
+    // Mark well placed characters, and count the other ones in answer
+    for (index, (att, ans)) in attempt.iter().zip(answer.iter()).enumerate() {
+        if att == ans {
+            states[index] = 2;
+        } else {
+            acc[(ans - b'A') as usize] += 1;
+        }
+    }
+
+    // Mark misplaced characters while their counter is non-null
+    for (index, att) in attempt.iter().enumerate() {
+        let count = &mut acc[(att - b'A') as usize];
+        if states[index] == 0 && *count > 0 {
+            states[index] = 1;
+            *count -= 1;
+        }
+    }
+
+    Pattern::from_digits(&states[..attempt.len()])
+}
+
+/// Patterns of every word of a dict attempted against every answer.
+pub struct FeedbackMatrix {
+    /// Row of each word (answers then allowed)
+    rows: HashMap<String, usize>,
+    /// Number of answers, i.e. size of a row
+    width: usize,
+    /// Patterns, row by row
+    patterns: Vec<Pattern>,
+}
+
+impl FeedbackMatrix {
+    /// Compute the feedback matrix of a dict.
+    pub fn of_dict(dict: &Dict) -> Result<Self, Error> {
+        if dict.word_size() > Pattern::MAX_SIZE {
+            return Err(Error::PatternTooLong(dict.word_size()));
+        }
+
+        let width = dict.answers.len();
+        let patterns = dict
+            .par_iter()
+            .flat_map_iter(|word| {
+                dict.answers
+                    .iter()
+                    .map(move |answer| feedback(word.as_bytes(), answer.as_bytes()))
+            })
+            .collect();
+
+        let rows = dict
+            .answers
+            .iter()
+            .chain(dict.allowed.iter())
+            .enumerate()
+            .map(|(index, word)| (word.clone(), index))
+            .collect();
+
+        Ok(FeedbackMatrix {
+            rows,
+            width,
+            patterns,
+        })
+    }
+
+    /// Check whether this matrix was computed for the given dict, i.e. each
+    /// word of the dict (answers then allowed) has its row.
+    ///
+    /// Weights of the dict are ignored, since they do not change feedbacks.
+    pub fn is_of_dict(&self, dict: &Dict) -> bool {
+        self.width == dict.answers.len()
+            && self.rows.len() == dict.len()
+            && dict
+                .answers
+                .iter()
+                .chain(dict.allowed.iter())
+                .enumerate()
+                .all(|(index, word)| self.rows.get(word) == Some(&index))
+    }
+
+    /// Return the patterns of a word attempted against each answer, if the
+    /// word is in the dict.
+    pub fn row(&self, word: &str) -> Option<&[Pattern]> {
+        self.rows
+            .get(word)
+            .map(|&row| &self.patterns[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Return the pattern of a word attempted against the answer at given
+    /// index, if the word is in the dict.
+    pub fn get(&self, word: &str, answer: usize) -> Option<Pattern> {
+        self.row(word).and_then(|row| row.get(answer).copied())
+    }
+}
@@ -1,21 +1,39 @@
-use crate::answers::{Answer, Answers, DictAnswers};
+use crate::answers::{Answer, Answers, DictAnswers, WordSet};
 use crate::attempt::{Attempt, Attempts};
+use crate::book::OpeningBook;
+use crate::cache::GameCache;
 use crate::errors::Error;
+use crate::feedback::FeedbackMatrix;
+use crate::knowledge::{Hint, Knowledge};
 use crate::options::Options;
+use crate::record::{AttemptRecord, GameRecord};
+use crate::state::Alphabet;
+use crate::strategy::{Heuristic, Strategy, Turn};
 use crate::tests::{Test, TestSuite};
+use crate::tree::DecisionTree;
 use crate::Dict;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-/// Description of a game current state
+/// Dict "answer" words with their index.
+type IndexedWords<'a> = Vec<(usize, &'a String)>;
 
+/// Description of a game current state
 pub struct Game<'a> {
     attempts: Attempts<'a>,
+    book: Option<&'a OpeningBook>,
     dict: &'a Dict,
-    dict_answers: DictAnswers,
-    tests: TestSuite,
+    dict_answers: Cow<'a, DictAnswers>,
+    feedback: Option<&'a FeedbackMatrix>,
+    options: Options,
+    strategy: Box<dyn Strategy>,
+    custom_strategy: bool,
+    tests: Cow<'a, TestSuite>,
+    tree: Option<&'a DecisionTree>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Guess {
     Solution(String),  // Word solution
     Candidate(String), // Candidate for next attempt and its likelyhood
@@ -25,12 +43,50 @@ pub enum Guess {
 
 impl<'a> Game<'a> {
     pub fn new(dict: &'a Dict, options: &'_ Options) -> Self {
+        Game {
+            custom_strategy: false,
+            ..Self::new_with_strategy(dict, options, options.scoring.strategy())
+        }
+    }
+
+    /// Create a game ranking guesses with a custom strategy.
+    ///
+    /// Scoring of the options is ignored, hence such a game can neither be
+    /// recorded nor use an opening book.
+    pub fn new_with_strategy(
+        dict: &'a Dict,
+        options: &'_ Options,
+        strategy: Box<dyn Strategy>,
+    ) -> Self {
         let tests = Test::for_dict(dict, options);
         Game {
             attempts: Vec::new(),
+            book: None,
             dict,
-            dict_answers: Answers::of_dict(dict, &tests),
-            tests,
+            dict_answers: Cow::Owned(Answers::of_dict(dict, &tests)),
+            feedback: None,
+            options: options.clone(),
+            strategy,
+            custom_strategy: true,
+            tests: Cow::Owned(tests),
+            tree: None,
+        }
+    }
+
+    /// Create a game from a cache, reusing its tests and answers instead of
+    /// computing them.
+    pub fn from_cache(cache: &'a GameCache) -> Self {
+        Game {
+            attempts: Vec::new(),
+            book: None,
+            dict: &cache.dict,
+            dict_answers: Cow::Borrowed(&cache.dict_answers),
+            feedback: None,
+            options: cache.options.clone(),
+            strategy: cache.options.scoring.strategy(),
+            custom_strategy: false,
+            tests: Cow::Borrowed(&cache.tests),
+            tree: None,
         }
     }
 
@@ -38,167 +94,461 @@ impl<'a> Game<'a> {
     pub fn new_with_tests(dict: &'a Dict, tests: TestSuite) -> Self {
         Game {
             attempts: Vec::new(),
+            book: None,
             dict,
-            dict_answers: Answers::of_dict(dict, &tests),
-            tests,
+            dict_answers: Cow::Owned(Answers::of_dict(dict, &tests)),
+            feedback: None,
+            options: Options::default(),
+            strategy: Box::new(Heuristic),
+            custom_strategy: false,
+            tests: Cow::Owned(tests),
+            tree: None,
         }
     }
 
-    pub fn add(&mut self, attempt: Attempt<'a>) {
-        // TODO: check attempted word is in dict
+    /// Resume a game recorded with the same dict and word size.
+    ///
+    /// Recorded attempts are checked again when added to the game.
+    pub fn from_record(dict: &'a Dict, record: &'a GameRecord) -> Result<Self, Error> {
+        if record.fingerprint != dict.fingerprint() {
+            return Err(Error::UnrelatedRecord(
+                dict.fingerprint(),
+                record.fingerprint,
+            ));
+        }
+        if record.word_size != dict.word_size() {
+            return Err(Error::InvalidRecordSize(dict.word_size(), record.word_size));
+        }
+
+        let mut game = Self::new(dict, &record.options);
+        for attempt in record.attempts.iter() {
+            game.add(Attempt(&attempt.word, attempt.pattern.states()))?;
+        }
+        Ok(game)
+    }
+
+    /// Record the game so that it can be saved and resumed later.
+    ///
+    /// Games with a custom strategy are rejected, as they would be resumed
+    /// with the strategy of their options.
+    pub fn record(&self) -> Result<GameRecord, Error> {
+        if self.custom_strategy {
+            return Err(Error::CustomStrategy);
+        }
+        Ok(GameRecord {
+            word_size: self.dict.word_size(),
+            attempts: self
+                .attempts
+                .iter()
+                .map(|attempt| {
+                    Ok(AttemptRecord {
+                        word: attempt.0.to_owned(),
+                        pattern: attempt.pattern()?,
+                    })
+                })
+                .collect::<Result<Vec<AttemptRecord>, Error>>()?,
+            options: self.options.clone(),
+            fingerprint: self.dict.fingerprint(),
+        })
+    }
+
+    /// Add an attempt to the game.
+    ///
+    /// The attempt is rejected if:
+    /// * its word or states do not have the size of dict words
+    /// * its word is not in dict (unless unknown words are allowed in options)
+    /// * revealed letters of options are not uppercase letters within words
+    /// * it misses a revealed letter, or a hint of previous attempts in hard mode
+    /// * its states contradict the states of previous attempts
+    pub fn add(&mut self, attempt: Attempt<'a>) -> Result<(), Error> {
+        self.check(&attempt, &self.attempts)?;
+        let others: Vec<&Attempt> = self.attempts.iter().collect();
+        Self::check_consistency(&others, &attempt)?;
         self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Remove the last attempt of the game and return it.
+    pub fn undo(&mut self) -> Option<Attempt<'a>> {
+        self.attempts.pop()
+    }
+
+    /// Keep only the first attempts of the game.
+    pub fn truncate(&mut self, len: usize) {
+        self.attempts.truncate(len);
     }
 
-    pub fn dict(&self) -> &Dict {
+    /// Replace the attempt at given index and return the previous one.
+    ///
+    /// The new attempt is checked the same way as in `add`: against hints of
+    /// the attempts preceding it, and for consistency with all other attempts.
+    /// In hard mode, the following attempts must also reuse the hints it gives.
+    pub fn replace(&mut self, index: usize, attempt: Attempt<'a>) -> Result<Attempt<'a>, Error> {
+        if index >= self.attempts.len() {
+            return Err(Error::InvalidAttemptIndex(index, self.attempts.len()));
+        }
+        self.check(&attempt, &self.attempts[..index])?;
+        let others: Vec<&Attempt> = self
+            .attempts
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, attempt)| attempt)
+            .collect();
+        Self::check_consistency(&others, &attempt)?;
+
+        let previous = std::mem::replace(&mut self.attempts[index], attempt);
+        if self.options.hard_mode {
+            for later in index + 1..self.attempts.len() {
+                let word = self.attempts[later].0;
+                if let Some(hint) = self
+                    .knowledge_of(&self.attempts[..later])
+                    .violated_hint(word)
+                {
+                    self.attempts[index] = previous;
+                    return Err(Error::IgnoredHint(hint, word.to_owned()));
+                }
+            }
+        }
+        Ok(previous)
+    }
+
+    /// Check the size of an attempt, that its word is in dict and that it
+    /// reuses revealed letters (and hints of previous attempts in hard mode).
+    fn check(&self, attempt: &Attempt, previous: &[Attempt]) -> Result<(), Error> {
+        let size = self.dict.word_size();
+        let word_size = attempt.0.chars().count();
+        if word_size != size {
+            return Err(Error::InvalidSize(size, word_size, attempt.0.to_owned()));
+        }
+        if attempt.1.len() != size {
+            return Err(Error::InvalidPatternSize(
+                size,
+                attempt.1.len(),
+                attempt
+                    .1
+                    .iter()
+                    .map(|state| Alphabet::default().symbol(state))
+                    .collect(),
+            ));
+        }
+
+        if !self.options.allow_unknown_words && !self.dict.contains(attempt.0) {
+            return Err(Error::UnknownWord(attempt.0.to_owned()));
+        }
+
+        self.check_revealed()?;
+        if let Some((position, letter)) = self
+            .options
+            .revealed
+            .iter()
+            .find(|(position, letter)| attempt.0.chars().nth(*position) != Some(*letter))
+        {
+            return Err(Error::MissingRevealedLetter(
+                *position,
+                *letter,
+                attempt.0.to_owned(),
+            ));
+        }
+
+        if self.options.hard_mode {
+            if let Some(hint) = self.knowledge_of(previous).violated_hint(attempt.0) {
+                return Err(Error::IgnoredHint(hint, attempt.0.to_owned()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that revealed letters are uppercase letters (A to Z) at
+    /// positions within dict words.
+    fn check_revealed(&self) -> Result<(), Error> {
+        let size = self.dict.word_size();
+        match self
+            .options
+            .revealed
+            .iter()
+            .find(|(position, letter)| *position >= size || !letter.is_ascii_uppercase())
+        {
+            Some((position, letter)) => Err(Error::InvalidRevealedLetter(*position, *letter)),
+            None => Ok(()),
+        }
+    }
+
+    /// Check that the states of an attempt do not contradict other attempts.
+    fn check_consistency(others: &[&Attempt], attempt: &Attempt) -> Result<(), Error> {
+        // Merge answers of all attempts to tests on letters of all attempted words
+        let options = Options {
+            tests_xfix_lengths: 0,
+            ..Options::default()
+        };
+        let attempts = || others.iter().copied().chain(std::iter::once(attempt));
+        let consistent = attempts()
+            .flat_map(|attempt| Test::for_word(attempt.0, &options))
+            .all(|test| {
+                attempts()
+                    .map(|attempt| attempt.answers(&test))
+                    .try_fold(Answer::Unknown, |prev, answer| prev + answer?)
+                    .is_ok()
+            });
+        if !consistent {
+            return Err(Error::InconsistentAttempt(attempt.0.to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// Use precomputed feedbacks of the dict to filter and score words.
+    pub fn set_feedback_matrix(&mut self, matrix: &'a FeedbackMatrix) -> Result<(), Error> {
+        if !matrix.is_of_dict(self.dict) {
+            return Err(Error::UnrelatedFeedbackMatrix);
+        }
+        self.feedback = Some(matrix);
+        Ok(())
+    }
+
+    /// Use the guesses of an opening book computed for the dict and options
+    /// of the game on the first turns.
+    ///
+    /// The book must be loaded by the caller, e.g. with `OpeningBook::from_file`.
+    /// Games with a custom strategy are rejected, books being computed with
+    /// the strategy of their options.
+    pub fn set_opening_book(&mut self, book: &'a OpeningBook) -> Result<(), Error> {
+        if self.custom_strategy {
+            return Err(Error::CustomStrategy);
+        }
+        if book.fingerprint != self.dict.fingerprint() || book.options != self.options {
+            return Err(Error::UnrelatedOpeningBook);
+        }
+        self.book = Some(book);
+        Ok(())
+    }
+
+    /// Change the letters revealed before the first attempt, e.g. to play
+    /// another answer.
+    pub(crate) fn set_revealed(&mut self, revealed: Vec<(usize, char)>) {
+        self.options.revealed = revealed;
+    }
+
+    /// Attempt the guesses of a decision tree while attempts follow it.
+    pub fn set_decision_tree(&mut self, tree: &'a DecisionTree) -> Result<(), Error> {
+        if tree.fingerprint != self.dict.fingerprint() {
+            return Err(Error::UnrelatedDecisionTree);
+        }
+        if let Some(guess) = tree.unknown_guess(self.dict) {
+            return Err(Error::UnknownWord(guess.to_owned()));
+        }
+        self.tree = Some(tree);
+        Ok(())
+    }
+
+    pub fn dict(&self) -> &'a Dict {
         self.dict
     }
 
-    pub fn attempts(&self) -> &Attempts {
+    pub fn attempts(&self) -> &Attempts<'_> {
         &self.attempts
     }
 
+    /// Check whether guesses are ranked with a custom strategy rather than
+    /// with the scoring of the options.
+    pub fn has_custom_strategy(&self) -> bool {
+        self.custom_strategy
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Check whether a word contains all revealed letters (and reuses all
+    /// hints in hard mode), and thus can be attempted.
+    pub fn is_playable(&self, word: &str) -> bool {
+        self.is_playable_with(&self.knowledge(), word)
+    }
+
+    fn is_playable_with(&self, knowledge: &Knowledge, word: &str) -> bool {
+        self.options
+            .revealed
+            .iter()
+            .all(|(position, letter)| word.chars().nth(*position) == Some(*letter))
+            && (!self.options.hard_mode || knowledge.violated_hint(word).is_none())
+    }
+
+    /// Return the first hint of the game a word does not reuse, if any.
+    pub fn violated_hint(&self, word: &str) -> Option<Hint> {
+        self.knowledge().violated_hint(word)
+    }
+
+    /// Combine the states of all attempts and revealed letters into a
+    /// knowledge of the solution.
+    pub fn knowledge(&self) -> Knowledge {
+        self.knowledge_of(&self.attempts)
+    }
+
+    fn knowledge_of(&self, attempts: &[Attempt]) -> Knowledge {
+        let mut knowledge = Knowledge::of_attempts(attempts, self.dict.word_size());
+        for (position, letter) in self.options.revealed.iter() {
+            knowledge.reveal(*position, *letter);
+        }
+        knowledge
+    }
+
     /// Compute the answer of each test given known attempts.
+    ///
+    /// Tests unanswered by every single attempt may still be answered by
+    /// combining results from all attempts (e.g. a prefix whose letters were
+    /// all found well placed by different attempts).
     pub fn known_answers(&self) -> Result<Answers, Error> {
+        self.check_revealed()?;
+        let knowledge = self.knowledge();
         self.tests
             .iter()
             .map(|test| {
-                self.attempts
+                let answer = self
+                    .attempts
                     .iter()
                     .map(|attempt| attempt.answers(test))
-                    .fold(Ok(Answer::Unknown), |acc, answer| match acc {
-                        Err(_) => acc,
-                        Ok(prev) => match answer {
-                            Err(_) => answer,
-                            Ok(new) => prev + new,
-                        },
-                    })
+                    .try_fold(Answer::Unknown, |prev, answer| prev + answer?)?;
+                match answer {
+                    Answer::Unknown => Ok(knowledge.answers(test)),
+                    _ => Ok(answer),
+                }
             })
             .collect()
+    }
 
-        // TODO: post-process answers to check if we can answer some more by combining
-        // results from all attempts.
-        // Example: answer unknown prefix/suffix if all associated AT tests were answered.
+    /// List the answers still compatible with all attempts of the game.
+    pub fn candidates(&self) -> Result<Vec<&'a String>, Error> {
+        let known_answers = self.known_answers()?;
+        let (compatible_words, _) = self.partition_answers(&known_answers, &self.knowledge());
+        Ok(compatible_words.into_iter().map(|(_, word)| word).collect())
+    }
+
+    /// Split dict "answer" words (with their index) between the ones
+    /// compatible with current answers and with the states of each attempt,
+    /// and the other ones. Words which are not playable are neither solutions
+    /// nor sacrifices, and are left out.
+    fn partition_answers(
+        &self,
+        known_answers: &Answers,
+        knowledge: &Knowledge,
+    ) -> (IndexedWords<'a>, IndexedWords<'a>) {
+        let compatible = self.dict_answers.compatible(known_answers);
+        self.dict
+            .answers
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| self.is_playable_with(knowledge, word))
+            .partition(|(index, word)| {
+                compatible.contains(*index)
+                    && self
+                        .attempts
+                        .iter()
+                        .all(|attempt| self.is_compatible(attempt, *index, word))
+            })
     }
 
     /// Compute the most relevant guess to attempt at next try
     pub fn guess_next(&self) -> Result<Guess, Error> {
+        self.check_revealed()?;
+
+        // 0 - Follow the opening book or the decision tree of the game, if any
+        // (the book is left out once revealed letters change, e.g. in simulations)
+        if let Some(guess) = self
+            .book
+            .filter(|book| book.options == self.options)
+            .and_then(|book| book.lookup(&self.attempts))
+        {
+            return Ok(guess.clone());
+        }
+        if let Some(tree) = self.tree.and_then(|tree| tree.lookup(&self.attempts)) {
+            if self.is_playable(&tree.guess) {
+                return Ok(if self.candidates()?.len() == 1 {
+                    Guess::Solution(tree.guess.clone())
+                } else if tree.is_candidate() {
+                    Guess::Candidate(tree.guess.clone())
+                } else {
+                    Guess::Sacrifice(tree.guess.clone())
+                });
+            }
+        }
+
         // 1 - Compute current answers
         let known_answers = self.known_answers()?;
+        let knowledge = self.knowledge();
 
         // 2 - Filter dict "answer" words to keep only ones compatibles with
-        // current answers
-        type WordAnswersList<'a> = Vec<(&'a String, &'a Answers)>;
-        let (compatible_words, incompatible_words): (WordAnswersList, WordAnswersList) = self
-            .dict
-            .answers
-            .iter()
-            .zip(self.dict_answers.iter())
-            .partition(|(_, word_answers)| {
-                known_answers
-                    .iter()
-                    .zip(word_answers.iter())
-                    .all(|(known_answer, word_answer)| (*known_answer + *word_answer).is_ok())
-            });
+        // current answers and with the states of each attempt
+        let (compatible_words, incompatible_words) =
+            self.partition_answers(&known_answers, &knowledge);
 
-        // 3 - Count compatible words (N)
+        // 3 - Count compatible words, and sum their weights (N)
         match compatible_words.len() {
             0 => Ok(Guess::NoSolution),
-            1 => Ok(Guess::Solution(compatible_words[0].0.clone())),
-            n => {
-                let is_answered: Vec<bool> = known_answers
+            1 => Ok(Guess::Solution(compatible_words[0].1.clone())),
+            _ => {
+                let weights: Vec<f64> = compatible_words
                     .iter()
-                    .map(|answer| *answer != Answer::Unknown)
+                    .map(|(index, _)| self.dict.weight(*index))
                     .collect();
+                let n: f64 = weights.iter().sum();
 
-                // 4 - For each unknown test, count the number of compatible words which answer Yes (n)
-                let test_positive_count = compatible_words
+                // 4 - For each unknown test, sum the weights of compatible words which answer Yes (n)
+                let compatible: WordSet =
+                    compatible_words.iter().map(|(index, _)| *index).collect();
+                let test_positive_count: Vec<f64> = known_answers
                     .iter()
-                    .map(|(_, answers)| {
-                        answers
-                            .iter()
-                            .zip(is_answered.iter())
-                            .map(|(answer, ignore)| {
-                                if !*ignore && *answer == Answer::Yes {
-                                    1
-                                } else {
-                                    0
-                                }
-                            })
-                            .collect::<Vec<u64>>()
-                    })
-                    .reduce(|count_a, count_b| {
-                        count_a
-                            .iter()
-                            .zip(count_b.iter())
-                            .map(|(a, b)| a + b)
-                            .collect()
+                    .enumerate()
+                    .map(|(test, answer)| {
+                        let yes = self.dict_answers.yes(test);
+                        match (answer, self.dict.weights()) {
+                            (Answer::Unknown, None) => yes.intersection_len(&compatible) as f64,
+                            (Answer::Unknown, Some(weights)) => yes
+                                .intersection(&compatible)
+                                .map(|index| weights[index])
+                                .sum(),
+                            _ => 0.,
+                        }
                     })
-                    .unwrap();
+                    .collect();
 
                 // 5 - For each unknown test, give a weight w = - n ( n - N )
                 //     This law give the highest weight to tests which partition compatible answers in half
-                let test_weight: Vec<u64> = test_positive_count
-                    .iter()
-                    .map(|c| c * (n as u64 - c))
-                    .collect();
+                let test_weight: Vec<f64> =
+                    test_positive_count.iter().map(|c| c * (n - c)).collect();
 
-                // 6 - Iterate over all words (answer + allowed) and compute for each its own score by
-                //     iterating over all tests and for each:
-                //     - compute the probability "p" that this word will answer the test
-                //     - add to the word score: s += p * w
+                // 6 - Iterate over all words (answer + allowed) and compute for each its own score
+                //     using the strategy of the game.
+                let turn = Turn {
+                    tests: &self.tests,
+                    compatible: compatible_words.iter().map(|(_, word)| *word).collect(),
+                    indices: compatible_words.iter().map(|(index, _)| *index).collect(),
+                    weights,
+                    feedback: self.feedback,
+                    test_weights: test_weight,
+                };
+                let score = |word: &String| self.strategy.score(&turn, word);
 
+                // Among candidates sharing the best score, prefer the most likely one
                 let best_candidate = compatible_words
                     .par_iter()
-                    .map(|(word, _)| {
-                        // Compute list of probabilities for this word
-                        (
-                            *word,
-                            self.tests
-                                .iter()
-                                .map(|test| match test {
-                                    Test::At(_, _) => {
-                                        if test.run(word) {
-                                            26
-                                        } else {
-                                            1
-                                        }
-                                    }
-                                    _ => 26,
-                                })
-                                .zip(test_weight.iter())
-                                .map(|(probability, weight)| probability * *weight)
-                                .sum::<u64>(),
-                        )
+                    .map(|(index, word)| (*word, score(word), self.dict.weight(*index)))
+                    .max_by(|(_, lhs, lhs_weight), (_, rhs, rhs_weight)| {
+                        lhs.total_cmp(rhs).then(lhs_weight.total_cmp(rhs_weight))
                     })
-                    .max_by_key(|(_, score)| *score)
                     .unwrap();
 
                 let best_sacrifice = incompatible_words
                     .par_iter()
-                    .map(|(word, _)| *word)
-                    .chain(self.dict.allowed.par_iter())
-                    .map(|word| {
-                        // Compute list of probabilities for this word
-                        (
-                            word,
-                            self.tests
-                                .iter()
-                                .map(|test| match test {
-                                    Test::At(_, _) => {
-                                        if test.run(word) {
-                                            26
-                                        } else {
-                                            1
-                                        }
-                                    }
-                                    _ => 26,
-                                })
-                                .zip(test_weight.iter())
-                                .map(|(probability, weight)| probability * *weight)
-                                .sum::<u64>(),
-                        )
-                    })
-                    .max_by_key(|(_, score)| *score);
+                    .map(|(_, word)| *word)
+                    .chain(
+                        self.dict
+                            .allowed
+                            .par_iter()
+                            .filter(|word| self.is_playable_with(&knowledge, word)),
+                    )
+                    .map(|word| (word, score(word)))
+                    .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs));
 
                 match best_sacrifice {
                     Some((word, score)) if score > best_candidate.1 => {
@@ -209,4 +559,16 @@ impl<'a> Game<'a> {
             }
         }
     }
+
+    /// Check whether the answer at given index would have produced the states
+    /// of an attempt, using precomputed feedbacks when available.
+    fn is_compatible(&self, attempt: &Attempt, index: usize, answer: &str) -> bool {
+        match self
+            .feedback
+            .and_then(|matrix| matrix.get(attempt.0, index))
+        {
+            Some(pattern) => attempt.pattern().ok() == Some(pattern),
+            None => attempt.is_compatible(answer),
+        }
+    }
 }
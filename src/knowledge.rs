@@ -0,0 +1,215 @@
+use crate::answers::Answer;
+use crate::attempt::Attempt;
+use crate::state::State;
+use crate::tests::Test;
+use std::collections::{HashMap, HashSet};
+
+/// A Hint is a piece of knowledge that must be reused in hard mode.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Hint {
+    Placed(usize, char),  // position, letter
+    Present(char, usize), // letter, minimum count
+}
+
+impl std::fmt::Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Placed(position, letter) => {
+                write!(f, "letter '{}' at position {}", &letter, &position)
+            }
+            Self::Present(letter, count) => {
+                write!(f, "letter '{}' at least {} time(s)", &letter, &count)
+            }
+        }
+    }
+}
+
+/// Knowledge on the solution gathered by combining all attempts of a game.
+#[derive(Debug, PartialEq)]
+pub struct Knowledge {
+    /// Letter known to be at each position
+    pub placed: Vec<Option<char>>,
+    /// Letters known not to be at each position
+    pub excluded: Vec<HashSet<char>>,
+    /// Minimum count of letters found in attempts
+    pub min_counts: HashMap<char, usize>,
+    /// Maximum count of letters found in attempts
+    pub max_counts: HashMap<char, usize>,
+}
+
+impl Knowledge {
+    /// Combine states of all attempts of a game whose words have given size.
+    pub fn of_attempts(attempts: &[Attempt], size: usize) -> Self {
+        let mut knowledge = Knowledge {
+            placed: vec![None; size],
+            excluded: vec![HashSet::new(); size],
+            min_counts: HashMap::new(),
+            max_counts: HashMap::new(),
+        };
+
+        for attempt in attempts.iter() {
+            let mut found = HashMap::new();
+            let mut missing = HashSet::new();
+
+            for (position, (letter, state)) in attempt.0.chars().zip(attempt.1.iter()).enumerate() {
+                match state {
+                    State::Yes => {
+                        if let Some(placed) = knowledge.placed.get_mut(position) {
+                            *placed = Some(letter);
+                        }
+                    }
+                    State::Meh | State::No => {
+                        if let Some(excluded) = knowledge.excluded.get_mut(position) {
+                            excluded.insert(letter);
+                        }
+                    }
+                }
+                match state {
+                    State::Yes | State::Meh => *found.entry(letter).or_insert(0) += 1,
+                    State::No => {
+                        missing.insert(letter);
+                    }
+                }
+            }
+
+            // Letters found in attempt appear at least as many times in solution
+            for (&letter, &count) in found.iter() {
+                let min_count = knowledge.min_counts.entry(letter).or_insert(0);
+                *min_count = std::cmp::max(*min_count, count);
+            }
+            // Letters marked as missing appear exactly as many times as found
+            for &letter in missing.iter() {
+                let count = found.get(&letter).copied().unwrap_or(0);
+                let max_count = knowledge.max_counts.entry(letter).or_insert(count);
+                *max_count = std::cmp::min(*max_count, count);
+            }
+        }
+
+        knowledge
+    }
+
+    /// Add a letter known to be at a given position.
+    pub fn reveal(&mut self, position: usize, letter: char) {
+        if let Some(placed) = self.placed.get_mut(position) {
+            *placed = Some(letter);
+        }
+    }
+
+    /// Minimum count of a letter in the solution.
+    pub fn min_count(&self, letter: char) -> usize {
+        let placed = self
+            .placed
+            .iter()
+            .filter(|placed| **placed == Some(letter))
+            .count();
+        std::cmp::max(placed, self.min_counts.get(&letter).copied().unwrap_or(0))
+    }
+
+    /// Maximum count of a letter in the solution.
+    pub fn max_count(&self, letter: char) -> usize {
+        let possible = self
+            .placed
+            .iter()
+            .zip(self.excluded.iter())
+            .filter(|(placed, excluded)| {
+                placed.is_none_or(|placed| placed == letter) && !excluded.contains(&letter)
+            })
+            .count();
+        std::cmp::min(
+            possible,
+            self.max_counts.get(&letter).copied().unwrap_or(possible),
+        )
+    }
+
+    /// List hints given by the knowledge: well placed letters, then letters
+    /// known to be present.
+    pub fn hints(&self) -> Vec<Hint> {
+        let mut present: Vec<(char, usize)> = self
+            .placed
+            .iter()
+            .flatten()
+            .chain(self.min_counts.keys())
+            .map(|letter| (*letter, self.min_count(*letter)))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        present.sort_unstable();
+        present.dedup();
+
+        self.placed
+            .iter()
+            .enumerate()
+            .filter_map(|(position, letter)| letter.map(|letter| Hint::Placed(position, letter)))
+            .chain(
+                present
+                    .into_iter()
+                    .map(|(letter, count)| Hint::Present(letter, count)),
+            )
+            .collect()
+    }
+
+    /// Return the first hint a word does not reuse, if any.
+    pub fn violated_hint(&self, word: &str) -> Option<Hint> {
+        self.hints().into_iter().find(|hint| match hint {
+            Hint::Placed(position, letter) => word.chars().nth(*position) != Some(*letter),
+            Hint::Present(letter, count) => word.chars().filter(|c| c == letter).count() < *count,
+        })
+    }
+
+    /// Answer a test using the combined knowledge.
+    pub fn answers(&self, test: &Test) -> Answer {
+        match test {
+            Test::At(letter, position) => match self.placed.get(*position) {
+                None => Answer::No,
+                Some(Some(placed)) if placed == letter => Answer::Yes,
+                Some(Some(_)) => Answer::No,
+                Some(None) if self.excluded[*position].contains(letter) => Answer::No,
+                Some(None) if self.max_count(*letter) == 0 => Answer::No,
+                Some(None) => Answer::Unknown,
+            },
+            Test::HasAtLeast(letter, count) => {
+                if self.min_count(*letter) >= *count as usize {
+                    Answer::Yes
+                } else if self.max_count(*letter) < *count as usize {
+                    Answer::No
+                } else {
+                    Answer::Unknown
+                }
+            }
+            Test::HasAtMost(letter, count) => {
+                if self.max_count(*letter) <= *count as usize {
+                    Answer::Yes
+                } else if self.min_count(*letter) > *count as usize {
+                    Answer::No
+                } else {
+                    Answer::Unknown
+                }
+            }
+            Test::HasPrefix(prefix) => self.answers_xfix(prefix, 0),
+            Test::HasSuffix(suffix) => {
+                let size = self.placed.len();
+                match size.checked_sub(suffix.chars().count()) {
+                    Some(start) => self.answers_xfix(suffix, start),
+                    None => Answer::No,
+                }
+            }
+        }
+    }
+
+    /// Answer whether the solution contains a given string at a given position,
+    /// by combining the answers of the associated At tests.
+    fn answers_xfix(&self, xfix: &str, start: usize) -> Answer {
+        let answers: Vec<Answer> = xfix
+            .chars()
+            .enumerate()
+            .map(|(index, letter)| self.answers(&Test::At(letter, start + index)))
+            .collect();
+
+        if answers.contains(&Answer::No) {
+            Answer::No
+        } else if answers.iter().all(|answer| *answer == Answer::Yes) {
+            Answer::Yes
+        } else {
+            Answer::Unknown
+        }
+    }
+}
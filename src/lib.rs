@@ -1,14 +1,36 @@
 pub mod answers;
 pub mod attempt;
+pub mod book;
+pub mod cache;
 pub mod dict;
+pub mod dict_set;
 pub mod errors;
+pub mod feedback;
 pub mod game;
+pub mod knowledge;
+pub mod normalize;
 pub mod options;
+pub mod pattern;
+pub mod record;
+pub mod simulation;
 pub mod state;
+pub mod strategy;
 pub mod tests;
+pub mod tree;
 
 pub use answers::{Answer, Answers};
-pub use dict::Dict;
+pub use book::OpeningBook;
+pub use cache::GameCache;
+pub use dict::{Dict, TextOptions};
+pub use dict_set::DictSet;
+pub use feedback::FeedbackMatrix;
 pub use game::{Game, Guess};
-pub use options::Options;
+pub use knowledge::{Hint, Knowledge};
+pub use normalize::NormalizationReport;
+pub use options::{Options, Scoring};
+pub use pattern::Pattern;
+pub use record::GameRecord;
+pub use simulation::{simulate, Report};
+pub use strategy::{Strategy, Turn};
 pub use tests::{Test, TestSuite};
+pub use tree::DecisionTree;
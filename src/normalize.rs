@@ -0,0 +1,40 @@
+/// Words of a list altered by normalization.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NormalizationReport {
+    /// Original and normalized form of each word whose letters were changed
+    /// (beyond upper-casing)
+    pub changed: Vec<(String, String)>,
+    /// Original form of each word dropped as a duplicate after
+    /// normalization, and the normalized word it duplicates
+    pub merged: Vec<(String, String)>,
+}
+
+impl NormalizationReport {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.merged.is_empty()
+    }
+}
+
+/// Normalize a word to ASCII uppercase letters: upper-case it, strip
+/// diacritics (É -> E, Ç -> C...) and expand ligatures (Œ -> OE, Æ -> AE).
+///
+/// Characters which cannot be normalized are kept as is.
+pub fn normalize(word: &str) -> String {
+    let mut normalized = String::with_capacity(word.len());
+    for character in word.chars().flat_map(|c| c.to_uppercase()) {
+        match character {
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => normalized.push('A'),
+            'Æ' => normalized.push_str("AE"),
+            'Ç' => normalized.push('C'),
+            'È' | 'É' | 'Ê' | 'Ë' => normalized.push('E'),
+            'Ì' | 'Í' | 'Î' | 'Ï' => normalized.push('I'),
+            'Ñ' => normalized.push('N'),
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => normalized.push('O'),
+            'Œ' => normalized.push_str("OE"),
+            'Ù' | 'Ú' | 'Û' | 'Ü' => normalized.push('U'),
+            'Ý' | 'Ÿ' => normalized.push('Y'),
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
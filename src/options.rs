@@ -1,15 +1,55 @@
+use crate::strategy::{Entropy, Heuristic, Minimax, Strategy};
+use serde::{Deserialize, Serialize};
+
+/// Built-in strategies used to rank words when computing the next guess.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Scoring {
+    /// Weight tests splitting compatible answers in half
+    Heuristic,
+    /// Maximize the entropy of the feedback patterns over compatible answers
+    Entropy,
+    /// Minimize the size of the largest group of answers sharing the same feedback
+    Minimax,
+}
+
+impl Scoring {
+    /// Build the strategy implementing this scoring.
+    pub fn strategy(&self) -> Box<dyn Strategy> {
+        match self {
+            Self::Heuristic => Box::new(Heuristic),
+            Self::Entropy => Box::new(Entropy),
+            Self::Minimax => Box::new(Minimax),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub struct Options {
     // Max length of prefixes/suffixes searched for in tests
     pub tests_xfix_lengths: usize,
     // Minimum percentage of words matching a test for it to be kept
     pub tests_keep_ratio: f32,
+    // Scoring used to select the next guess
+    pub scoring: Scoring,
+    // Accept attempted words which are not in the dictionary
+    pub allow_unknown_words: bool,
+    // Letters revealed before the first attempt (position, letter), which
+    // every attempt must contain (e.g. first letter in Sutom)
+    pub revealed: Vec<(usize, char)>,
+    // Require every attempt to reuse the hints revealed by previous attempts
+    pub hard_mode: bool,
 }
 
-impl Options {
-    pub fn default() -> Self {
+impl Default for Options {
+    fn default() -> Self {
         Options {
             tests_xfix_lengths: 4,
             tests_keep_ratio: 0.01,
+            scoring: Scoring::Heuristic,
+            allow_unknown_words: false,
+            revealed: Vec::new(),
+            hard_mode: false,
         }
     }
 }
@@ -0,0 +1,138 @@
+use crate::errors::Error;
+use crate::state::{Alphabet, State, States};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A Pattern packs the States of an attempt in a single integer.
+///
+/// States are encoded as base-3 digits (No = 0, Meh = 1, Yes = 2), the state
+/// of the first character being the least significant digit. The code is
+/// prefixed with a leading 1 digit so that the size of the pattern can be
+/// recovered.
+///
+/// Patterns are serialized as the digits of their states (e.g. "2100"), so
+/// that this encoding does not leak into persisted files.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pattern(u32);
+
+impl Pattern {
+    /// Maximum number of states which can be packed in a pattern.
+    pub const MAX_SIZE: usize = 19;
+
+    /// Build a pattern from the digit of each state (No = 0, Meh = 1, Yes = 2).
+    ///
+    /// Digits are expected to be valid and at most MAX_SIZE.
+    pub(crate) fn from_digits(digits: &[u8]) -> Self {
+        Pattern(
+            digits
+                .iter()
+                .rev()
+                .fold(1, |code, digit| code * 3 + *digit as u32),
+        )
+    }
+
+    /// Pattern of a word whose characters are all correctly placed.
+    pub fn solved(size: usize) -> Self {
+        Self::from_digits(&vec![2; size])
+    }
+
+    /// Return the number of states in this pattern.
+    pub fn size(&self) -> usize {
+        let mut size = 0;
+        let mut code = self.0;
+        while code > 1 {
+            code /= 3;
+            size += 1;
+        }
+        size
+    }
+
+    /// Render the states of this pattern with the symbols of an alphabet.
+    pub fn to_string_with(&self, alphabet: &Alphabet) -> String {
+        self.states()
+            .iter()
+            .map(|state| alphabet.symbol(state))
+            .collect()
+    }
+
+    /// Return the state of each character.
+    pub fn states(&self) -> States {
+        let mut code = self.0;
+        let mut states = States::with_capacity(self.size());
+        while code > 1 {
+            states.push(match code % 3 {
+                0 => State::No,
+                1 => State::Meh,
+                _ => State::Yes,
+            });
+            code /= 3;
+        }
+        states
+    }
+
+    /// Check whether all characters are correctly placed.
+    pub fn is_solved(&self) -> bool {
+        *self == Self::solved(self.size())
+    }
+}
+
+impl TryFrom<&States> for Pattern {
+    type Error = Error;
+
+    fn try_from(states: &States) -> Result<Self, Self::Error> {
+        if states.len() > Self::MAX_SIZE {
+            return Err(Error::PatternTooLong(states.len()));
+        }
+        let digits: Vec<u8> = states
+            .iter()
+            .map(|state| match state {
+                State::No => 0,
+                State::Meh => 1,
+                State::Yes => 2,
+            })
+            .collect();
+        Ok(Self::from_digits(&digits))
+    }
+}
+
+impl From<Pattern> for States {
+    fn from(pattern: Pattern) -> Self {
+        pattern.states()
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string_with(&Alphabet::DIGITS))
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let digits = String::deserialize(deserializer)?;
+        digits
+            .chars()
+            .map(|symbol| {
+                Alphabet::DIGITS
+                    .state(symbol)
+                    .ok_or_else(|| Error::UnknownSymbol(symbol, digits.clone()))
+            })
+            .collect::<Result<States, Error>>()
+            .and_then(|states| Pattern::try_from(&states))
+            .map_err(|err| D::Error::custom(format!("{}", err)))
+    }
+}
+
+impl std::fmt::Display for Pattern {
+    /// Display the pattern as colored squares: 🟥 (Yes), 🟡 (Meh) and 🟦 (No).
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.states()
+            .iter()
+            .try_for_each(|state| write!(f, "{}", Alphabet::SUTOM.symbol(state)))
+    }
+}
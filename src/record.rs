@@ -0,0 +1,45 @@
+use crate::options::Options;
+use crate::pattern::Pattern;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// Owned record of an attempt.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AttemptRecord {
+    /// Attempted word
+    pub word: String,
+    /// States of each character of the word
+    pub pattern: Pattern,
+}
+
+/// Owned record of a game which can be persisted and resumed later against
+/// the same dictionnary.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GameRecord {
+    /// Size of words
+    pub word_size: usize,
+    /// Attempts of the game, in order
+    pub attempts: Vec<AttemptRecord>,
+    /// Options of the game
+    pub options: Options,
+    /// Fingerprint of the dictionnary of the game
+    pub fingerprint: u64,
+}
+
+impl GameRecord {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::attempt::Attempt;
+use crate::errors::Error;
+use crate::game::{Game, Guess};
+use std::collections::HashMap;
+
+/// Outcome of the simulation of a game for a given answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordResult {
+    /// Answer of the simulated game
+    pub answer: String,
+    /// Words attempted by the solver, in order
+    pub guesses: Vec<String>,
+    /// Whether the answer was found within the maximum number of attempts
+    pub solved: bool,
+}
+
+/// Results of the simulation of games for every answer of a dict.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// Maximum number of attempts allowed to find an answer
+    pub max_attempts: usize,
+    /// Result of each simulated game, in the order of dict answers
+    pub results: Vec<WordResult>,
+}
+
+impl Report {
+    /// Average number of attempts needed to find solved answers.
+    pub fn average(&self) -> f64 {
+        let solved: Vec<&WordResult> = self.results.iter().filter(|r| r.solved).collect();
+        if solved.is_empty() {
+            return 0.;
+        }
+        solved.iter().map(|r| r.guesses.len()).sum::<usize>() as f64 / solved.len() as f64
+    }
+
+    /// Number of answers solved in each number of attempts: the n-th element
+    /// counts answers found at the (n+1)-th attempt.
+    pub fn distribution(&self) -> Vec<usize> {
+        let mut distribution = vec![0; self.max_attempts];
+        self.results
+            .iter()
+            .filter(|result| result.solved)
+            .for_each(|result| distribution[result.guesses.len() - 1] += 1);
+        distribution
+    }
+
+    /// Results of answers not found within the maximum number of attempts.
+    pub fn failures(&self) -> Vec<&WordResult> {
+        self.results
+            .iter()
+            .filter(|result| !result.solved)
+            .collect()
+    }
+}
+
+/// Play a game for every answer of its dict, attempting at each try the
+/// guess suggested by the game and the states given by the answer.
+///
+/// Letters revealed in the options of the game are taken from each answer at
+/// the same positions, as a game of this answer would reveal them.
+///
+/// Attempts and revealed letters of the game are restored when done.
+pub fn simulate<'a>(game: &mut Game<'a>, max_attempts: usize) -> Result<Report, Error> {
+    let mut attempts: Vec<Attempt<'a>> = Vec::with_capacity(game.attempts().len());
+    while let Some(attempt) = game.undo() {
+        attempts.push(attempt);
+    }
+    let revealed = game.options().revealed.clone();
+
+    let report = simulate_answers(game, max_attempts);
+    game.truncate(0);
+    game.set_revealed(revealed);
+    for attempt in attempts.into_iter().rev() {
+        game.add(attempt)?;
+    }
+    report
+}
+
+/// Simulate games of every answer, from a game without attempts.
+fn simulate_answers(game: &mut Game, max_attempts: usize) -> Result<Report, Error> {
+    let dict = game.dict();
+    let positions: Vec<usize> = game
+        .options()
+        .revealed
+        .iter()
+        .map(|(position, _)| *position)
+        .collect();
+    let words: HashMap<&str, &String> = dict
+        .answers
+        .iter()
+        .chain(dict.allowed.iter())
+        .map(|word| (word.as_str(), word))
+        .collect();
+
+    let mut results = Vec::with_capacity(dict.answers.len());
+    for answer in dict.answers.iter() {
+        game.truncate(0);
+        game.set_revealed(
+            positions
+                .iter()
+                .filter_map(|&position| Some((position, answer.chars().nth(position)?)))
+                .collect(),
+        );
+        let mut result = WordResult {
+            answer: answer.clone(),
+            guesses: Vec::new(),
+            solved: false,
+        };
+
+        while result.guesses.len() < max_attempts {
+            let guess = match game.guess_next()? {
+                Guess::Solution(word) | Guess::Candidate(word) | Guess::Sacrifice(word) => word,
+                Guess::NoSolution => break,
+            };
+            let word = *words
+                .get(guess.as_str())
+                .ok_or_else(|| Error::UnknownWord(guess.clone()))?;
+            let attempt = Attempt::from_answer(word, answer)?;
+            let solved = attempt.pattern()?.is_solved();
+
+            game.add(attempt)?;
+            result.guesses.push(guess);
+            if solved {
+                result.solved = true;
+                break;
+            }
+        }
+        results.push(result);
+    }
+
+    Ok(Report {
+        max_attempts,
+        results,
+    })
+}
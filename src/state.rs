@@ -1,5 +1,5 @@
 /// A State represents the answer given for a character in an attempt.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum State {
     /// Letter is correctly placed (RED).
     Yes,
@@ -11,3 +11,80 @@ pub enum State {
 
 /// A States contains the State of each individual character of an attempt.
 pub type States = Vec<State>;
+
+/// An Alphabet gives the symbol used to type each State.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alphabet {
+    pub yes: char,
+    pub meh: char,
+    pub no: char,
+}
+
+impl Alphabet {
+    /// Initials of the colors shown in game: Red, Yellow and Blue.
+    pub const COLORS: Alphabet = Alphabet {
+        yes: 'R',
+        meh: 'Y',
+        no: 'B',
+    };
+    /// Digits matching the encoding of patterns.
+    pub const DIGITS: Alphabet = Alphabet {
+        yes: '2',
+        meh: '1',
+        no: '0',
+    };
+    /// Plus for well placed, question mark for misplaced and minus for absent.
+    pub const SIGNS: Alphabet = Alphabet {
+        yes: '+',
+        meh: '?',
+        no: '-',
+    };
+
+    /// Squares shared by Sutom players.
+    pub const SUTOM: Alphabet = Alphabet {
+        yes: '🟥',
+        meh: '🟡',
+        no: '🟦',
+    };
+    /// Squares shared by Wordle players (dark theme).
+    pub const WORDLE: Alphabet = Alphabet {
+        yes: '🟩',
+        meh: '🟨',
+        no: '⬛',
+    };
+    /// Squares shared by Wordle players (light theme).
+    pub const WORDLE_LIGHT: Alphabet = Alphabet {
+        yes: '🟩',
+        meh: '🟨',
+        no: '⬜',
+    };
+
+    /// Return the State typed with given symbol (case insensitive).
+    pub fn state(&self, symbol: char) -> Option<State> {
+        let symbol = symbol.to_ascii_uppercase();
+        if symbol == self.yes.to_ascii_uppercase() {
+            Some(State::Yes)
+        } else if symbol == self.meh.to_ascii_uppercase() {
+            Some(State::Meh)
+        } else if symbol == self.no.to_ascii_uppercase() {
+            Some(State::No)
+        } else {
+            None
+        }
+    }
+
+    /// Return the symbol of a State.
+    pub fn symbol(&self, state: &State) -> char {
+        match state {
+            State::Yes => self.yes,
+            State::Meh => self.meh,
+            State::No => self.no,
+        }
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::COLORS
+    }
+}
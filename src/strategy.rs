@@ -0,0 +1,127 @@
+use crate::attempt::Attempt;
+use crate::feedback::FeedbackMatrix;
+use crate::pattern::Pattern;
+use crate::tests::{Test, TestSuite};
+use std::collections::HashMap;
+
+/// Snapshot of a game turn given to strategies to score words.
+pub struct Turn<'a> {
+    /// Tests of the game
+    pub tests: &'a TestSuite,
+    /// Answers still compatible with all attempts
+    pub compatible: Vec<&'a String>,
+    /// Index of each compatible answer in the dict answers
+    pub indices: Vec<usize>,
+    /// Relative frequency of each compatible answer
+    pub weights: Vec<f64>,
+    /// Precomputed feedbacks of the dict, if any
+    pub feedback: Option<&'a FeedbackMatrix>,
+    /// Weight of each test: highest for tests splitting compatible answers in half
+    pub test_weights: Vec<f64>,
+}
+
+impl<'a> Turn<'a> {
+    /// Total weight of compatible answers.
+    pub fn total_weight(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Group compatible answers by the pattern a word would produce when
+    /// attempted against them, and return the total weight of each group.
+    pub fn buckets(&self, word: &str) -> HashMap<Pattern, f64> {
+        let mut buckets = HashMap::new();
+        for (pattern, weight) in self.patterns(word).into_iter().zip(self.weights.iter()) {
+            if let Some(pattern) = pattern {
+                *buckets.entry(pattern).or_insert(0.) += weight;
+            }
+        }
+        buckets
+    }
+
+    /// Group compatible answers by the pattern a word would produce when
+    /// attempted against them, and return the number of answers of each
+    /// group, regardless of their weights.
+    pub fn bucket_sizes(&self, word: &str) -> HashMap<Pattern, usize> {
+        let mut buckets = HashMap::new();
+        for pattern in self.patterns(word).into_iter().flatten() {
+            *buckets.entry(pattern).or_insert(0) += 1;
+        }
+        buckets
+    }
+
+    /// Pattern a word would produce when attempted against each compatible
+    /// answer, if it can be computed.
+    fn patterns(&self, word: &str) -> Vec<Option<Pattern>> {
+        match self.feedback.and_then(|matrix| matrix.row(word)) {
+            Some(row) => self.indices.iter().map(|&index| Some(row[index])).collect(),
+            None => self
+                .compatible
+                .iter()
+                .map(|answer| Attempt::from_answer(word, answer).ok()?.pattern().ok())
+                .collect(),
+        }
+    }
+}
+
+/// A Strategy ranks the words which may be attempted at the next try.
+pub trait Strategy: Send + Sync {
+    /// Score a word for the given turn, the best word having the highest score.
+    fn score(&self, turn: &Turn, word: &str) -> f64;
+}
+
+/// Score words by the tests they are likely to answer, weighted by how well
+/// these tests split the compatible answers.
+pub struct Heuristic;
+
+impl Strategy for Heuristic {
+    /// Iterate over all tests and for each:
+    /// - compute the probability "p" that this word will answer the test
+    /// - add to the word score: s += p * w
+    fn score(&self, turn: &Turn, word: &str) -> f64 {
+        turn.tests
+            .iter()
+            .map(|test| match test {
+                Test::At(_, _) => {
+                    if test.run(word) {
+                        26.
+                    } else {
+                        1.
+                    }
+                }
+                _ => 26.,
+            })
+            .zip(turn.test_weights.iter())
+            .map(|(probability, weight)| probability * *weight)
+            .sum::<f64>()
+    }
+}
+
+/// Score words by the Shannon entropy (in bits) of the distribution of
+/// feedbacks they would produce over compatible answers.
+pub struct Entropy;
+
+impl Strategy for Entropy {
+    fn score(&self, turn: &Turn, word: &str) -> f64 {
+        let total = turn.total_weight();
+        turn.buckets(word)
+            .values()
+            .map(|&weight| {
+                let p = weight / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+/// Score words by the size of the largest group of compatible answers
+/// sharing the same feedback, minimizing the worst case.
+///
+/// Weights of answers are ignored, the worst case not depending on them.
+pub struct Minimax;
+
+impl Strategy for Minimax {
+    fn score(&self, turn: &Turn, word: &str) -> f64 {
+        let worst = turn.bucket_sizes(word).into_values().max().unwrap_or(0);
+        -(worst as f64)
+    }
+}
@@ -1,9 +1,10 @@
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{Dict, Options};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Test {
     At(char, usize),      // letter, position
     HasAtLeast(char, u8), // letter, count
@@ -97,9 +98,7 @@ impl Test {
     // Validate a word against a given test
     pub fn run(&self, word: &str) -> bool {
         match self {
-            Self::At(letter, position) => {
-                word.chars().nth(*position).map_or(false, |l| l == *letter)
-            }
+            Self::At(letter, position) => word.chars().nth(*position) == Some(*letter),
             Self::HasAtLeast(letter, count) => {
                 word.chars().filter(|&l| l == *letter).count() >= *count as usize
             }
@@ -0,0 +1,330 @@
+use crate::attempt::Attempt;
+use crate::errors::Error;
+use crate::feedback::FeedbackMatrix;
+use crate::game::{Game, Guess};
+use crate::pattern::Pattern;
+use crate::state::Alphabet;
+use crate::Dict;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// A DecisionTree gives the word to attempt at each turn, given the patterns
+/// produced by previous attempts.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DecisionTree {
+    /// Fingerprint of the dictionnary the tree was built for
+    pub fingerprint: u64,
+    /// Word to attempt
+    pub guess: String,
+    /// Number of answers still compatible before attempting the guess
+    pub answers: usize,
+    /// Subtree following each pattern the guess may produce (except the
+    /// solved pattern), sorted by pattern
+    pub branches: Vec<(Pattern, DecisionTree)>,
+}
+
+impl DecisionTree {
+    /// Check whether the guess may be the solution.
+    pub fn is_candidate(&self) -> bool {
+        self.answers > self.branches.iter().map(|(_, tree)| tree.answers).sum()
+    }
+
+    /// Total number of attempts needed to find each of the answers.
+    pub fn cost(&self) -> usize {
+        self.answers
+            + self
+                .branches
+                .iter()
+                .map(|(_, tree)| tree.cost())
+                .sum::<usize>()
+    }
+
+    /// Average number of attempts needed to find an answer.
+    pub fn expected_guesses(&self) -> f64 {
+        self.cost() as f64 / self.answers as f64
+    }
+
+    /// Maximum number of attempts needed to find an answer.
+    pub fn depth(&self) -> usize {
+        1 + self
+            .branches
+            .iter()
+            .map(|(_, tree)| tree.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the subtree following a pattern produced by the guess.
+    pub fn branch(&self, pattern: &Pattern) -> Option<&DecisionTree> {
+        self.branches
+            .binary_search_by(|(branch, _)| branch.cmp(pattern))
+            .ok()
+            .map(|index| &self.branches[index].1)
+    }
+
+    /// Return the subtree reached by a sequence of attempts, if each attempt
+    /// follows the guess of the tree and is not solved.
+    pub fn lookup(&self, attempts: &[Attempt]) -> Option<&DecisionTree> {
+        attempts.iter().try_fold(self, |tree, attempt| {
+            if attempt.0 != tree.guess {
+                return None;
+            }
+            tree.branch(&attempt.pattern().ok()?)
+        })
+    }
+
+    /// Return the first guess of the tree which is not a word of a dict, if any.
+    pub fn unknown_guess(&self, dict: &Dict) -> Option<&String> {
+        if !dict.contains(&self.guess) {
+            return Some(&self.guess);
+        }
+        self.branches
+            .iter()
+            .find_map(|(_, tree)| tree.unknown_guess(dict))
+    }
+
+    /// Compute the decision tree minimizing the expected number of attempts
+    /// to find an answer of the dict.
+    ///
+    /// The search is exhaustive over all words of the dict, and is thus only
+    /// tractable for small dicts.
+    pub fn optimal(dict: &Dict, matrix: &FeedbackMatrix) -> Result<Self, Error> {
+        if !matrix.is_of_dict(dict) {
+            return Err(Error::UnrelatedFeedbackMatrix);
+        }
+
+        let mut solver = Solver {
+            words: dict
+                .answers
+                .iter()
+                .chain(dict.allowed.iter())
+                .map(|word| (word, matrix.row(word).unwrap()))
+                .collect(),
+            solved: Pattern::solved(dict.word_size()),
+            fingerprint: dict.fingerprint(),
+            memo: HashMap::new(),
+        };
+        let answers: Vec<usize> = (0..dict.answers.len()).collect();
+        solver.solve(&answers);
+        Ok(solver.tree(&answers))
+    }
+
+    /// Build the tree of the guesses a game makes for each of the answers
+    /// compatible with its attempts.
+    ///
+    /// Attempts of the game are restored when done.
+    pub fn of_game(game: &mut Game) -> Result<Self, Error> {
+        let dict = game.dict();
+        let words: HashMap<&str, &String> = dict
+            .answers
+            .iter()
+            .chain(dict.allowed.iter())
+            .map(|word| (word.as_str(), word))
+            .collect();
+
+        let answers = game.candidates()?;
+        let len = game.attempts().len();
+        let tree = Self::grow(game, dict.fingerprint(), &words, &answers);
+        game.truncate(len);
+        tree
+    }
+
+    /// Build the subtree of a set of answers compatible with the attempts of
+    /// a game.
+    fn grow<'a>(
+        game: &mut Game<'a>,
+        fingerprint: u64,
+        words: &HashMap<&str, &'a String>,
+        answers: &[&'a String],
+    ) -> Result<Self, Error> {
+        let guess = match game.guess_next()? {
+            Guess::Solution(word) | Guess::Candidate(word) | Guess::Sacrifice(word) => word,
+            Guess::NoSolution => return Err(Error::NoProgress(answers.len())),
+        };
+        let word = *words
+            .get(guess.as_str())
+            .ok_or_else(|| Error::UnknownWord(guess.clone()))?;
+
+        let mut buckets: BTreeMap<Pattern, Vec<&'a String>> = BTreeMap::new();
+        for answer in answers.iter() {
+            let pattern = Attempt::from_answer(word, answer)?.pattern()?;
+            if !pattern.is_solved() {
+                buckets.entry(pattern).or_default().push(answer);
+            }
+        }
+        if buckets.values().any(|bucket| bucket.len() == answers.len()) {
+            return Err(Error::NoProgress(answers.len()));
+        }
+
+        let mut branches = Vec::with_capacity(buckets.len());
+        for (pattern, bucket) in buckets.into_iter() {
+            game.add(Attempt(word, pattern.states()))?;
+            let tree = Self::grow(game, fingerprint, words, &bucket);
+            game.undo();
+            branches.push((pattern, tree?));
+        }
+
+        Ok(DecisionTree {
+            fingerprint,
+            guess,
+            answers: answers.len(),
+            branches,
+        })
+    }
+
+    /// Export the tree as JSON, patterns being rendered with an alphabet.
+    pub fn to_json(&self, alphabet: &Alphabet) -> String {
+        serde_json::to_string_pretty(&self.to_value(alphabet)).unwrap()
+    }
+
+    fn to_value(&self, alphabet: &Alphabet) -> Value {
+        let branches: Map<String, Value> = self
+            .branches
+            .iter()
+            .map(|(pattern, tree)| (pattern.to_string_with(alphabet), tree.to_value(alphabet)))
+            .collect();
+
+        let mut node = Map::new();
+        node.insert("guess".to_string(), Value::from(self.guess.as_str()));
+        node.insert("answers".to_string(), Value::from(self.answers));
+        node.insert("branches".to_string(), Value::Object(branches));
+        Value::Object(node)
+    }
+
+    /// Export the tree as a Graphviz DOT graph, patterns being rendered with
+    /// an alphabet. Guesses which cannot be the solution are dashed.
+    pub fn to_dot(&self, alphabet: &Alphabet) -> String {
+        let mut dot = String::from("digraph {\n");
+        self.write_dot(&mut dot, alphabet, &mut 0);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Write the nodes and edges of the tree, numbering nodes in preorder,
+    /// and return the number of its root.
+    fn write_dot(&self, dot: &mut String, alphabet: &Alphabet, count: &mut usize) -> usize {
+        let node = *count;
+        *count += 1;
+        dot.push_str(&format!(
+            "    n{} [label=\"{} ({})\"{}];\n",
+            node,
+            &self.guess,
+            &self.answers,
+            if self.is_candidate() {
+                ""
+            } else {
+                ", style=dashed"
+            }
+        ));
+
+        for (pattern, tree) in self.branches.iter() {
+            let child = tree.write_dot(dot, alphabet, count);
+            dot.push_str(&format!(
+                "    n{} -> n{} [label=\"{}\"];\n",
+                node,
+                child,
+                pattern.to_string_with(alphabet)
+            ));
+        }
+        node
+    }
+}
+
+/// Exhaustive search of the optimal decision tree, memoizing the best guess
+/// of each set of answers.
+struct Solver<'d> {
+    /// Each word of the dict (answers then allowed) with its feedback row
+    words: Vec<(&'d String, &'d [Pattern])>,
+    /// Pattern of a found answer
+    solved: Pattern,
+    /// Fingerprint of the dict
+    fingerprint: u64,
+    /// Minimum cost and best word of each set of answers already solved
+    memo: HashMap<Vec<usize>, (usize, usize)>,
+}
+
+impl Solver<'_> {
+    /// Minimum cost of a set of answers reachable by at least 2 attempts.
+    fn lower_bound(answers: usize) -> usize {
+        2 * answers - 1
+    }
+
+    /// Group answers by the pattern a word produces, leaving out the answer
+    /// equal to the word.
+    fn partition(&self, word: usize, answers: &[usize]) -> BTreeMap<Pattern, Vec<usize>> {
+        let row = self.words[word].1;
+        let mut buckets: BTreeMap<Pattern, Vec<usize>> = BTreeMap::new();
+        for &answer in answers.iter() {
+            if row[answer] != self.solved {
+                buckets.entry(row[answer]).or_default().push(answer);
+            }
+        }
+        buckets
+    }
+
+    /// Compute the minimum total number of attempts to find each answer of a
+    /// set, assuming they are all compatible with previous attempts.
+    fn solve(&mut self, answers: &[usize]) -> usize {
+        if answers.len() == 1 {
+            // Answers are the first words of the dict
+            self.memo.insert(answers.to_vec(), (1, answers[0]));
+            return 1;
+        }
+        if let Some((cost, _)) = self.memo.get(answers) {
+            return *cost;
+        }
+
+        // Rank words by the lower bound of their cost, skipping words which
+        // do not split answers
+        let mut splits: Vec<(usize, usize, Vec<Vec<usize>>)> = (0..self.words.len())
+            .map(|word| {
+                let buckets: Vec<Vec<usize>> =
+                    self.partition(word, answers).into_values().collect();
+                let bound = answers.len()
+                    + buckets
+                        .iter()
+                        .map(|bucket| Self::lower_bound(bucket.len()))
+                        .sum::<usize>();
+                (bound, word, buckets)
+            })
+            .filter(|(_, _, buckets)| buckets.len() > 1 || buckets[0].len() < answers.len())
+            .collect();
+        splits.sort_by_key(|(bound, word, _)| (*bound, *word));
+
+        let mut best = (usize::MAX, 0);
+        for (bound, word, buckets) in splits.into_iter() {
+            if bound >= best.0 {
+                break;
+            }
+            let mut cost = bound;
+            for bucket in buckets.iter() {
+                cost += self.solve(bucket) - Self::lower_bound(bucket.len());
+                if cost >= best.0 {
+                    break;
+                }
+            }
+            if cost < best.0 {
+                best = (cost, word);
+            }
+        }
+
+        self.memo.insert(answers.to_vec(), best);
+        best.0
+    }
+
+    /// Build the decision tree of a solved set of answers.
+    fn tree(&self, answers: &[usize]) -> DecisionTree {
+        let (_, word) = self.memo[answers];
+        DecisionTree {
+            fingerprint: self.fingerprint,
+            guess: self.words[word].0.clone(),
+            answers: answers.len(),
+            branches: self
+                .partition(word, answers)
+                .into_iter()
+                .map(|(pattern, bucket)| (pattern, self.tree(&bucket)))
+                .collect(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use mutsolver_core::{
-    Answer::{No, Yes},
+    answers::WordSet,
+    Answer::{No, Unknown, Yes},
     Answers,
 };
 mod fixtures;
@@ -17,8 +18,11 @@ fn test_answers_dict() {
     let tests = fixture_testsuite();
     let dict = fixture_dict();
     let dict_answers = Answers::of_dict(&dict, &tests);
+    assert_eq!(dict_answers.len(), 4);
     assert_eq!(
-        dict_answers,
+        (0..dict_answers.len())
+            .map(|word| dict_answers.word(word))
+            .collect::<Vec<Answers>>(),
         vec![
             Answers(vec![Yes, Yes, Yes, No, No]),
             Answers(vec![Yes, Yes, No, Yes, No]),
@@ -26,4 +30,52 @@ fn test_answers_dict() {
             Answers(vec![Yes, Yes, No, No, Yes]),
         ]
     );
+    assert_eq!(
+        dict_answers.yes(2).iter().collect::<Vec<usize>>(),
+        vec![0, 2]
+    );
+}
+
+#[test]
+fn test_answers_compatible() {
+    let tests = fixture_testsuite();
+    let dict = fixture_dict();
+    let dict_answers = Answers::of_dict(&dict, &tests);
+
+    let unknown = Answers(vec![Unknown; 5]);
+    assert_eq!(
+        dict_answers
+            .compatible(&unknown)
+            .iter()
+            .collect::<Vec<usize>>(),
+        vec![0, 1, 2, 3]
+    );
+    let known = Answers(vec![Yes, Unknown, No, Unknown, Unknown]);
+    assert_eq!(
+        dict_answers
+            .compatible(&known)
+            .iter()
+            .collect::<Vec<usize>>(),
+        vec![1, 3]
+    );
+}
+
+#[test]
+fn test_word_set() {
+    let full = WordSet::full(130);
+    assert_eq!(full.len(), 130);
+    assert!(full.contains(129));
+    assert!(!full.contains(130));
+
+    let mut words: WordSet = [3, 64, 127].into_iter().collect();
+    assert_eq!(words.intersection_len(&full), 3);
+    let mut other = WordSet::empty(130);
+    other.insert(64);
+    other.insert(129);
+    assert_eq!(words.intersection(&other).collect::<Vec<usize>>(), vec![64]);
+
+    words.subtract(&other);
+    assert_eq!(words.iter().collect::<Vec<usize>>(), vec![3, 127]);
+    words.intersect(&other);
+    assert!(words.is_empty());
 }
@@ -1,4 +1,9 @@
-use mutsolver_core::{attempt::Attempt, errors::Error, state::State, Answer, Test};
+use mutsolver_core::{
+    attempt::{from_grid, to_grid, Attempt},
+    errors::Error,
+    state::{Alphabet, State},
+    Answer, Test,
+};
 use State::{Meh, No, Yes};
 
 #[test]
@@ -15,6 +20,13 @@ fn test_new_attempt_mixed_yes_meh() {
     assert_eq!(attempt.1, vec![Yes, No, Yes, No, Meh, Meh, Yes, No])
 }
 
+#[test]
+fn test_new_attempt_exhausted_meh() {
+    let answer = "AIRES";
+    let attempt = Attempt::from_answer("ARBRE", answer).unwrap();
+    assert_eq!(attempt.1, vec![Yes, Meh, No, No, Meh])
+}
+
 #[test]
 fn test_new_attempt_invalid_sizes() {
     let answer = "ABCD";
@@ -181,3 +193,93 @@ fn test_attempt_answer_hassuffix() {
         _ => panic!(),
     };
 }
+
+#[test]
+fn test_new_attempt_from_pattern() {
+    let attempt = Attempt::from_pattern("RIVIERES", "RBRBYYRB").unwrap();
+    assert_eq!(attempt.0, "RIVIERES");
+    assert_eq!(attempt.1, vec![Yes, No, Yes, No, Meh, Meh, Yes, No]);
+
+    let attempt = Attempt::from_pattern("ABCD", "rybb").unwrap();
+    assert_eq!(attempt.1, vec![Yes, Meh, No, No]);
+}
+
+#[test]
+fn test_new_attempt_from_pattern_alphabets() {
+    let attempt = Attempt::from_pattern_with("ABCD", "2100", &Alphabet::DIGITS).unwrap();
+    assert_eq!(attempt.1, vec![Yes, Meh, No, No]);
+
+    let attempt = Attempt::from_pattern_with("ABCD", "-?+-", &Alphabet::SIGNS).unwrap();
+    assert_eq!(attempt.1, vec![No, Meh, Yes, No]);
+
+    let alphabet = Alphabet {
+        yes: 'G',
+        meh: 'Y',
+        no: 'X',
+    };
+    let attempt = Attempt::from_pattern_with("ABCD", "GXYG", &alphabet).unwrap();
+    assert_eq!(attempt.1, vec![Yes, No, Meh, Yes]);
+}
+
+#[test]
+fn test_new_attempt_from_pattern_invalid() {
+    match Attempt::from_pattern("ABCD", "RYB") {
+        Err(Error::InvalidPatternSize(4, 3, _)) => (),
+        _ => panic!(),
+    }
+    match Attempt::from_pattern("ABCD", "RYGB") {
+        Err(Error::UnknownSymbol('G', _)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_attempts_from_sutom_grid() {
+    let grid = "SUTOM #42 3/6\n\n🟥🟦🟡🟦🟦\n🟥🟥🟦🟡🟦\n🟥🟥🟥🟥🟥\n\nhttps://sutom.nocle.fr";
+    let attempts = from_grid(grid, &["ARBRE", "AIMER", "AIRES"]).unwrap();
+    assert_eq!(attempts.len(), 3);
+    assert_eq!(attempts[0].0, "ARBRE");
+    assert_eq!(attempts[0].1, vec![Yes, No, Meh, No, No]);
+    assert_eq!(attempts[1].1, vec![Yes, Yes, No, Meh, No]);
+    assert_eq!(attempts[2].1, vec![Yes; 5]);
+}
+
+#[test]
+fn test_attempts_from_wordle_grid() {
+    let grid = "Wordle 250 2/6\n\n⬛🟨⬛⬛🟩\n🟩🟩🟩🟩🟩";
+    let attempts = from_grid(grid, &["CRANE", "SHORE"]).unwrap();
+    assert_eq!(attempts[0].1, vec![No, Meh, No, No, Yes]);
+
+    let grid = "⬜🟨⬜⬜🟩\n🟩🟩🟩🟩🟩";
+    let attempts = from_grid(grid, &["CRANE", "SHORE"]).unwrap();
+    assert_eq!(attempts[0].1, vec![No, Meh, No, No, Yes]);
+}
+
+#[test]
+fn test_attempts_from_grid_invalid() {
+    match from_grid("🟥🟦🟡🟦🟦", &["ARBRE", "AIRES"]) {
+        Err(Error::InvalidGridSize(2, 1)) => (),
+        _ => panic!(),
+    }
+    match from_grid("🟥🟦🟡🟦", &["ARBRE"]) {
+        Err(Error::InvalidPatternSize(5, 4, _)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_attempts_to_grid() {
+    let attempts = vec![
+        Attempt::from_answer("ARBRE", "AIRES").unwrap(),
+        Attempt::from_answer("AIRES", "AIRES").unwrap(),
+    ];
+    let grid = to_grid(&attempts, &Alphabet::SUTOM);
+    assert_eq!(grid, "🟥🟡🟦🟦🟡\n🟥🟥🟥🟥🟥");
+
+    let parsed = from_grid(&grid, &["ARBRE", "AIRES"]).unwrap();
+    assert_eq!(parsed[0].1, attempts[0].1);
+    assert_eq!(
+        to_grid(&attempts, &Alphabet::WORDLE),
+        "🟩🟨⬛⬛🟨\n🟩🟩🟩🟩🟩"
+    );
+}
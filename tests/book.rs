@@ -0,0 +1,104 @@
+use mutsolver_core::{attempt::Attempt, errors::Error, Game, Guess, OpeningBook, Options, Scoring};
+mod fixtures;
+use fixtures::{fixture_dict, fixture_sacrifice_dict};
+
+#[test]
+fn test_book_of_game() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        scoring: Scoring::Entropy,
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    game.add(Attempt::from_answer("CARE", "DARE").unwrap())
+        .unwrap();
+    let book = OpeningBook::of_game(&mut game, true).unwrap();
+
+    // Attempts of the game are restored
+    assert_eq!(game.attempts().len(), 1);
+    assert_eq!(game.attempts()[0].0, "CARE");
+    game.truncate(0);
+    assert_eq!(book.fingerprint, dict.fingerprint());
+    assert_eq!(book.options, options);
+    assert_eq!(book.first, Guess::Sacrifice("BCDF".to_string()));
+    assert_eq!(book.second.len(), 4);
+
+    for answer in dict.answers.iter() {
+        game.add(Attempt::from_answer("BCDF", answer).unwrap())
+            .unwrap();
+        assert_eq!(
+            book.lookup(game.attempts()),
+            Some(&Guess::Solution(answer.clone()))
+        );
+        game.truncate(0);
+    }
+
+    // Book only covers the first turns
+    game.add(Attempt::from_answer("CARE", "DARE").unwrap())
+        .unwrap();
+    assert!(book.lookup(game.attempts()).is_none());
+}
+
+#[test]
+fn test_book_first_turn_only() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    let book = OpeningBook::of_game(&mut game, false).unwrap();
+
+    assert_eq!(book.first, game.guess_next().unwrap());
+    assert!(book.second.is_empty());
+}
+
+#[test]
+fn test_game_with_book() {
+    let dict = fixture_dict();
+    let options = Options::default();
+    let book = OpeningBook::of_game(&mut Game::new(&dict, &options), true).unwrap();
+
+    let mut game = Game::new(&dict, &options);
+    let mut reference = Game::new(&dict, &options);
+    game.set_opening_book(&book).unwrap();
+
+    assert_eq!(game.guess_next().unwrap(), reference.guess_next().unwrap());
+    for game in [&mut game, &mut reference] {
+        game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap())
+            .unwrap();
+    }
+    assert_eq!(game.guess_next().unwrap(), reference.guess_next().unwrap());
+}
+
+#[test]
+fn test_book_unrelated() {
+    let dict = fixture_dict();
+    let book = OpeningBook::of_game(&mut Game::new(&dict, &Options::default()), false).unwrap();
+
+    let other_dict = fixture_sacrifice_dict();
+    let mut game = Game::new(&other_dict, &Options::default());
+    match game.set_opening_book(&book) {
+        Err(Error::UnrelatedOpeningBook) => (),
+        _ => panic!(),
+    }
+
+    let options = Options {
+        hard_mode: true,
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    match game.set_opening_book(&book) {
+        Err(Error::UnrelatedOpeningBook) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_book_file() {
+    let dict = fixture_sacrifice_dict();
+    let book = OpeningBook::of_game(&mut Game::new(&dict, &Options::default()), true).unwrap();
+
+    let path = std::env::temp_dir().join("mutsolver_test_book_file.json");
+    book.to_file(&path).unwrap();
+    let loaded = OpeningBook::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, book);
+}
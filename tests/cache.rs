@@ -0,0 +1,117 @@
+use mutsolver_core::{
+    attempt::Attempt, errors::Error, Answers, Game, GameCache, Options, Scoring, Test,
+};
+mod fixtures;
+use fixtures::fixture_dict;
+
+#[test]
+fn test_cache_new() {
+    let options = Options {
+        scoring: Scoring::Entropy,
+        ..Options::default()
+    };
+    let cache = GameCache::new(fixture_dict(), &options);
+
+    assert_eq!(cache.fingerprint, fixture_dict().fingerprint());
+    assert!(cache.is_of_dict(&fixture_dict()));
+    assert_eq!(cache.options, options);
+    assert_eq!(cache.tests, Test::for_dict(&fixture_dict(), &options));
+    assert_eq!(
+        cache.dict_answers,
+        Answers::of_dict(&fixture_dict(), &cache.tests)
+    );
+}
+
+#[test]
+fn test_game_from_cache() {
+    let dict = fixture_dict();
+    let options = Options::default();
+    let cache = GameCache::new(fixture_dict(), &options);
+
+    let mut game = Game::new(&dict, &options);
+    let mut cached = Game::from_cache(&cache);
+    assert_eq!(cached.options(), &options);
+    assert_eq!(cached.guess_next().unwrap(), game.guess_next().unwrap());
+
+    game.add(Attempt::from_answer("ABOUTI", "ABSOLU").unwrap())
+        .unwrap();
+    cached
+        .add(Attempt::from_answer("ABOUTI", "ABSOLU").unwrap())
+        .unwrap();
+    assert_eq!(
+        cached.known_answers().unwrap(),
+        game.known_answers().unwrap()
+    );
+    assert_eq!(cached.guess_next().unwrap(), game.guess_next().unwrap());
+}
+
+#[test]
+fn test_cache_file() {
+    let cache = GameCache::new(fixture_dict(), &Options::default());
+
+    let path = std::env::temp_dir().join("mutsolver_test_cache_file.bin");
+    cache.to_file(&path).unwrap();
+    let loaded = GameCache::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, cache);
+}
+
+#[test]
+fn test_cache_file_fingerprint() {
+    let mut cache = GameCache::new(fixture_dict(), &Options::default());
+    cache.fingerprint += 1;
+
+    let path = std::env::temp_dir().join("mutsolver_test_cache_file_fingerprint.bin");
+    cache.to_file(&path).unwrap();
+    let loaded = GameCache::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    match loaded.map_err(|err| err.downcast::<Error>()) {
+        Err(Ok(err)) => match *err {
+            Error::CorruptedCache(expected, found) if found == expected + 1 => (),
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_cache_file_inconsistent() {
+    let mut cache = GameCache::new(fixture_dict(), &Options::default());
+    cache.tests.push(Test::At('A', 0));
+
+    let path = std::env::temp_dir().join("mutsolver_test_cache_file_inconsistent.bin");
+    cache.to_file(&path).unwrap();
+    let loaded = GameCache::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    match loaded.map_err(|err| err.downcast::<Error>()) {
+        Err(Ok(err)) => match *err {
+            Error::InconsistentCache => (),
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_cache_file_version() {
+    let cache = GameCache::new(fixture_dict(), &Options::default());
+
+    let path = std::env::temp_dir().join("mutsolver_test_cache_file_version.bin");
+    let mut writer = std::fs::File::create(&path).unwrap();
+    bincode::serialize_into(&mut writer, &(GameCache::VERSION + 1)).unwrap();
+    bincode::serialize_into(&mut writer, &cache).unwrap();
+    drop(writer);
+    let loaded = GameCache::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    match loaded.map_err(|err| err.downcast::<Error>()) {
+        Err(Ok(err)) => match *err {
+            Error::UnsupportedCacheVersion(expected, found) if found == expected + 1 => (),
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+}
@@ -1,5 +1,6 @@
+use mutsolver_core::dict::{read_words, validate, ListKind};
 use mutsolver_core::errors::DictError;
-use mutsolver_core::Dict;
+use mutsolver_core::{Dict, NormalizationReport, TextOptions};
 mod fixtures;
 use fixtures::fixture_dict;
 
@@ -19,6 +20,14 @@ fn test_dict_valid() {
     assert!(!dict.is_empty());
 }
 
+#[test]
+fn test_dict_contains() {
+    let dict = Dict::new(vecstr!["ABACAS", "ABADER"], vecstr!["ABAQUE"]).unwrap();
+    assert!(dict.contains("ABACAS"));
+    assert!(dict.contains("ABAQUE"));
+    assert!(!dict.contains("ABASIE"));
+}
+
 // When feature(assert_matches) is stabilized, use it instead of the match statement
 // in following tests.
 
@@ -86,3 +95,314 @@ fn test_serde() {
     let dict2 = bincode::deserialize(&bindict).unwrap();
     assert_eq!(dict, dict2);
 }
+
+#[test]
+fn test_fingerprint() {
+    let dict = fixture_dict();
+    assert_eq!(dict.fingerprint(), fixture_dict().fingerprint());
+
+    // Moving a word from answers to allowed changes the fingerprint
+    let mut answers = dict.answers.clone();
+    let allowed = vec![answers.pop().unwrap()];
+    let other = Dict::new(answers, allowed).unwrap();
+    assert_ne!(dict.fingerprint(), other.fingerprint());
+}
+
+#[test]
+fn test_dict_weights() {
+    let dict = Dict::new_with_weights(
+        vecstr!["ABACAS", "ABADER", "ABAQUE"],
+        vecstr!["ABATIS"],
+        vec![1., 2.5, 0.5],
+    )
+    .unwrap();
+    assert_eq!(dict.weights(), Some(&[1., 2.5, 0.5][..]));
+    assert_eq!(dict.weight(1), 2.5);
+
+    let unweighted = Dict::new(vecstr!["ABACAS", "ABADER", "ABAQUE"], vecstr!["ABATIS"]).unwrap();
+    assert_eq!(unweighted.weights(), None);
+    assert_eq!(unweighted.weight(1), 1.);
+    assert_ne!(dict.fingerprint(), unweighted.fingerprint());
+
+    let bindict = bincode::serialize(&dict).unwrap();
+    let dict2: Dict = bincode::deserialize(&bindict).unwrap();
+    assert_eq!(dict, dict2);
+}
+
+#[test]
+fn test_dict_weights_json() {
+    let dict: Dict =
+        serde_json::from_str(r#"{"answers": ["ABACAS", "ABADER"], "weights": [3, 1]}"#).unwrap();
+    assert_eq!(dict.weights(), Some(&[3., 1.][..]));
+
+    let json = serde_json::to_string(&dict).unwrap();
+    assert_eq!(
+        json,
+        r#"{"answers":["ABACAS","ABADER"],"allowed":[],"weights":[3.0,1.0]}"#
+    );
+    let bindict = bincode::serialize(&dict).unwrap();
+    assert_eq!(bincode::deserialize::<Dict>(&bindict).unwrap(), dict);
+
+    // Missing weights are left out of JSON
+    let dict: Dict = serde_json::from_str(r#"{"answers": ["ABACAS", "ABADER"]}"#).unwrap();
+    assert_eq!(dict.weights(), None);
+    assert_eq!(
+        serde_json::to_string(&dict).unwrap(),
+        r#"{"answers":["ABACAS","ABADER"],"allowed":[]}"#
+    );
+
+    let dict = serde_json::from_str::<Dict>(r#"{"answers": ["ABACAS", "ABADER"], "weights": [3]}"#);
+    assert!(dict.is_err());
+}
+
+#[test]
+fn test_dict_inconsistent_weights() {
+    let dict = Dict::new_with_weights(vecstr!["ABACAS", "ABADER"], vecstr!["ABAQUE"], vec![1.; 3]);
+    match dict {
+        Err(DictError::InconsistentWeights(2, 3)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_dict_invalid_weight() {
+    let dict = Dict::new_with_weights(vecstr!["ABACAS", "ABADER"], vec![], vec![1., -1.]);
+    match dict {
+        Err(DictError::InvalidWeight(weight, word)) if weight == -1. && word == "ABADER" => (),
+        _ => panic!(),
+    }
+
+    let dict = Dict::new_with_weights(vecstr!["ABACAS", "ABADER"], vec![], vec![f64::NAN, 1.]);
+    match dict {
+        Err(DictError::InvalidWeight(_, word)) if word == "ABACAS" => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_read_words() {
+    let text = "# Answers\n  abacas\nABADER  \n\n\tAbaque\nabaqué\nabc def\n";
+
+    let words = read_words(text.as_bytes(), &TextOptions::default()).unwrap();
+    assert_eq!(
+        words,
+        vecstr!["ABACAS", "ABADER", "ABAQUE", "ABAQUÉ", "ABC DEF"]
+    );
+
+    let options = TextOptions {
+        skip_invalid: true,
+        ..TextOptions::default()
+    };
+    let words = read_words(text.as_bytes(), &options).unwrap();
+    assert_eq!(words, vecstr!["ABACAS", "ABADER", "ABAQUE"]);
+
+    let options = TextOptions {
+        comment_prefix: "//".to_string(),
+        ..TextOptions::default()
+    };
+    let words = read_words(text.as_bytes(), &options).unwrap();
+    assert_eq!(words[0], "# ANSWERS");
+}
+
+#[test]
+fn test_dict_from_text_files() {
+    let answers = std::env::temp_dir().join("mutsolver_test_text_answers.txt");
+    let allowed = std::env::temp_dir().join("mutsolver_test_text_allowed.txt");
+    std::fs::write(&answers, "# Answers\nabacas\nabader\nabat\nabaqu3\n").unwrap();
+    std::fs::write(&allowed, "abatis\nabaissa\n").unwrap();
+
+    let strict = Dict::from_text_files(&answers, Some(&allowed), &TextOptions::default());
+    let options = TextOptions {
+        skip_invalid: true,
+        ..TextOptions::default()
+    };
+    let dict = Dict::from_text_files(&answers, Some(&allowed), &options).unwrap();
+    let answers_only = Dict::from_text_files(&answers, None, &options).unwrap();
+
+    std::fs::remove_file(&answers).unwrap();
+    std::fs::remove_file(&allowed).unwrap();
+
+    assert!(strict.is_err());
+    assert_eq!(dict.answers, vecstr!["ABACAS", "ABADER"]);
+    assert_eq!(dict.allowed, vecstr!["ABATIS"]);
+    assert!(answers_only.allowed.is_empty());
+}
+
+#[test]
+fn test_read_words_normalized() {
+    let text = "Pêche\npéché\nPECHE\nabaque\nabaqué\n";
+    let options = TextOptions {
+        normalize: true,
+        ..TextOptions::default()
+    };
+
+    let words = read_words(text.as_bytes(), &options).unwrap();
+    assert_eq!(words, vecstr!["PECHE", "ABAQUE"]);
+}
+
+#[test]
+fn test_dict_from_text_files_normalized() {
+    let answers = std::env::temp_dir().join("mutsolver_test_normalized_answers.txt");
+    let allowed = std::env::temp_dir().join("mutsolver_test_normalized_allowed.txt");
+    std::fs::write(&answers, "élève\ncœur\nCOEUR\nabcde\n").unwrap();
+    std::fs::write(&allowed, "ELEVE\nmaïss\n").unwrap();
+
+    let strict = Dict::from_text_files(&answers, Some(&allowed), &TextOptions::default());
+    let options = TextOptions {
+        normalize: true,
+        ..TextOptions::default()
+    };
+    let (dict, report) =
+        Dict::from_text_files_with_report(&answers, Some(&allowed), &options).unwrap();
+
+    std::fs::remove_file(&answers).unwrap();
+    std::fs::remove_file(&allowed).unwrap();
+
+    assert!(strict.is_err());
+    assert_eq!(dict.answers, vecstr!["ELEVE", "COEUR", "ABCDE"]);
+    assert_eq!(dict.allowed, vecstr!["MAISS"]);
+    assert_eq!(
+        report,
+        NormalizationReport {
+            changed: vec![
+                ("élève".to_string(), "ELEVE".to_string()),
+                ("cœur".to_string(), "COEUR".to_string()),
+                ("maïss".to_string(), "MAISS".to_string()),
+            ],
+            merged: vec![
+                ("COEUR".to_string(), "COEUR".to_string()),
+                ("ELEVE".to_string(), "ELEVE".to_string()),
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_validate_report() {
+    let answers = vecstr!["ABACAS", "ABADE", "ABAQUé", "ABATEE", "ABACAS", "abat"];
+    let allowed = vecstr!["ABATIS", "ABATEE", "ABACAS"];
+    let report = validate(&answers, &allowed, Some(&[1., 1., 0., 1., 1., 1.]));
+
+    let issues: Vec<(Option<(ListKind, usize)>, String)> = report
+        .issues
+        .iter()
+        .map(|issue| (issue.location, format!("{:?}", issue.error)))
+        .collect();
+    assert_eq!(
+        issues,
+        vec![
+            (
+                Some((ListKind::Answers, 1)),
+                "InconsistentSize(6, 5, \"ABADE\")".to_string()
+            ),
+            (
+                Some((ListKind::Answers, 2)),
+                "UnauthorizedCharacter('é', \"ABAQUé\")".to_string()
+            ),
+            (
+                Some((ListKind::Answers, 2)),
+                "InvalidWeight(0.0, \"ABAQUé\")".to_string()
+            ),
+            (
+                Some((ListKind::Answers, 4)),
+                "DuplicateWord(3, \"ABACAS\")".to_string()
+            ),
+            (
+                Some((ListKind::Answers, 5)),
+                "InconsistentSize(6, 4, \"abat\")".to_string()
+            ),
+            (
+                Some((ListKind::Answers, 5)),
+                "UnauthorizedCharacter('a', \"abat\")".to_string()
+            ),
+            (
+                Some((ListKind::Allowed, 1)),
+                "DuplicateWord(2, \"ABATEE\")".to_string()
+            ),
+            (
+                Some((ListKind::Allowed, 2)),
+                "DuplicateWord(3, \"ABACAS\")".to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        report.to_string().lines().next(),
+        Some("answers[1]: Size of 'ABADE' differs from expectation (6 != 5)")
+    );
+}
+
+#[test]
+fn test_validate_global_issues() {
+    let report = validate(&[], &["ABACAS".to_string()], None);
+    match report.issues.as_slice() {
+        [issue] if issue.location.is_none() => match issue.error {
+            DictError::MissingAnswers => (),
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+
+    let report = validate(&["ABACAS".to_string()], &[], Some(&[1., 2.]));
+    match report.issues.as_slice() {
+        [issue] if issue.location.is_none() => match issue.error {
+            DictError::InconsistentWeights(1, 2) => (),
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+
+    assert!(validate(&["ABACAS".to_string()], &["ABADER".to_string()], None).is_empty());
+}
+
+#[test]
+fn test_validate_file() {
+    let path = std::env::temp_dir().join("mutsolver_test_validate_file.json");
+    std::fs::write(
+        &path,
+        r#"{"answers": ["ABACAS", "ABACAS"], "allowed": ["ABADE"]}"#,
+    )
+    .unwrap();
+    let report = Dict::validate_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let locations: Vec<Option<(ListKind, usize)>> =
+        report.issues.iter().map(|issue| issue.location).collect();
+    assert_eq!(
+        locations,
+        vec![Some((ListKind::Answers, 1)), Some((ListKind::Allowed, 0))]
+    );
+}
+
+#[test]
+fn test_dict_read_text_files() {
+    let answers = std::env::temp_dir().join("mutsolver_test_read_text_answers.txt");
+    let allowed = std::env::temp_dir().join("mutsolver_test_read_text_allowed.txt");
+    std::fs::write(&answers, "élève\nELEVE\nabcde\nabc\n").unwrap();
+    std::fs::write(&allowed, "abcde\nzèbre\n").unwrap();
+
+    let options = TextOptions {
+        normalize: true,
+        skip_invalid: true,
+        ..TextOptions::default()
+    };
+    let (answers_read, allowed_read, _) =
+        Dict::read_text_files(&answers, Some(&allowed), &options).unwrap();
+    let raw = validate(
+        &read_words(
+            std::io::BufReader::new(std::fs::File::open(&answers).unwrap()),
+            &TextOptions::default(),
+        )
+        .unwrap(),
+        &[],
+        None,
+    );
+
+    std::fs::remove_file(&answers).unwrap();
+    std::fs::remove_file(&allowed).unwrap();
+
+    // Words read are the ones the dict is built from, which are valid
+    assert_eq!(answers_read, vecstr!["ELEVE", "ABCDE"]);
+    assert_eq!(allowed_read, vecstr!["ZEBRE"]);
+    assert!(validate(&answers_read, &allowed_read, None).is_empty());
+    assert!(!raw.is_empty());
+}
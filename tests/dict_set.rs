@@ -0,0 +1,158 @@
+use mutsolver_core::dict_set::lists_of_size;
+use mutsolver_core::errors::DictError;
+use mutsolver_core::{Dict, DictSet, TextOptions};
+
+macro_rules! vecstr {
+    ($($x:expr),*) => (vec![$($x.to_string()),*]);
+}
+
+#[test]
+fn test_dict_set_split() {
+    let dicts = DictSet::new(
+        vecstr!["ABACAS", "CHAT", "ABADER", "CHIEN", "LOUP"],
+        vecstr!["ABATIS", "ZEBU", "ZZZZZZZ"],
+    )
+    .unwrap();
+
+    assert_eq!(dicts.len(), 3);
+    assert!(!dicts.is_empty());
+    assert_eq!(dicts.sizes().collect::<Vec<usize>>(), vec![4, 5, 6]);
+
+    let dict = dicts.get(4).unwrap();
+    assert_eq!(dict.answers, vecstr!["CHAT", "LOUP"]);
+    assert_eq!(dict.allowed, vecstr!["ZEBU"]);
+    assert_eq!(dict.word_size(), 4);
+    assert_eq!(
+        dicts.get(6).unwrap(),
+        &Dict::new(vecstr!["ABACAS", "ABADER"], vecstr!["ABATIS"]).unwrap()
+    );
+    // Allowed words of a size without answers are left out
+    assert!(dicts.get(7).is_none());
+}
+
+#[test]
+fn test_dict_set_weights() {
+    let mut dicts = DictSet::new_with_weights(
+        vecstr!["ABACAS", "CHAT", "ABADER"],
+        vec![],
+        vec![1., 2., 3.],
+    )
+    .unwrap();
+    assert_eq!(dicts.get(4).unwrap().weights(), Some(&[2.][..]));
+
+    let dict = dicts.remove(6).unwrap();
+    assert_eq!(dict.weights(), Some(&[1., 3.][..]));
+    assert!(dicts.get(6).is_none());
+
+    match DictSet::new_with_weights(vecstr!["ABACAS", "CHAT"], vec![], vec![1.]) {
+        Err(DictError::InconsistentWeights(2, 1)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_dict_set_invalid() {
+    match DictSet::new(vec![], vecstr!["CHAT"]) {
+        Err(DictError::MissingAnswers) => (),
+        _ => panic!(),
+    }
+    match DictSet::new(vecstr!["ABACAS", "CHAT", "CHAT"], vec![]) {
+        Err(DictError::DuplicateWord(2, _)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_dict_set_serde() {
+    let json = r#"{"answers": ["CHAT", "ABACAS", "LOUP"], "allowed": ["ZEBU"]}"#;
+    let dicts: DictSet = serde_json::from_str(json).unwrap();
+    assert_eq!(dicts.sizes().collect::<Vec<usize>>(), vec![4, 6]);
+
+    // Words are serialized by increasing size
+    assert_eq!(
+        serde_json::to_string(&dicts).unwrap(),
+        r#"{"answers":["CHAT","LOUP","ABACAS"],"allowed":["ZEBU"]}"#
+    );
+
+    let bindicts = bincode::serialize(&dicts).unwrap();
+    let dicts2: DictSet = bincode::deserialize(&bindicts).unwrap();
+    assert_eq!(dicts, dicts2);
+
+    // A dict file is a valid set file
+    let dict = Dict::new(vecstr!["ABACAS", "ABADER"], vec![]).unwrap();
+    let dicts: DictSet = serde_json::from_str(&serde_json::to_string(&dict).unwrap()).unwrap();
+    assert_eq!(dicts.get(6), Some(&dict));
+}
+
+#[test]
+fn test_dict_set_file() {
+    let dicts = DictSet::new_with_weights(
+        vecstr!["ABACAS", "CHAT", "ABADER"],
+        vecstr!["ZEBU"],
+        vec![1., 2., 3.],
+    )
+    .unwrap();
+
+    let path = std::env::temp_dir().join("mutsolver_test_dict_set_file.json");
+    dicts.to_file(&path).unwrap();
+    let loaded = DictSet::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, dicts);
+}
+
+#[test]
+fn test_dict_set_from_text_files_normalized() {
+    let answers = std::env::temp_dir().join("mutsolver_test_dict_set_answers.txt");
+    let allowed = std::env::temp_dir().join("mutsolver_test_dict_set_allowed.txt");
+    std::fs::write(&answers, "êtes\nchat\nélève\n").unwrap();
+    std::fs::write(&allowed, "ETES\nzébu\n").unwrap();
+
+    let strict = DictSet::from_text_files(&answers, Some(&allowed), &TextOptions::default());
+    let options = TextOptions {
+        normalize: true,
+        ..TextOptions::default()
+    };
+    let (dicts, report) =
+        DictSet::from_text_files_with_report(&answers, Some(&allowed), &options).unwrap();
+
+    std::fs::remove_file(&answers).unwrap();
+    std::fs::remove_file(&allowed).unwrap();
+
+    assert!(strict.is_err());
+    assert_eq!(dicts.sizes().collect::<Vec<usize>>(), vec![4, 5]);
+    // Allowed words duplicating answers once normalized are merged into them
+    assert_eq!(dicts.get(4).unwrap().answers, vecstr!["ETES", "CHAT"]);
+    assert_eq!(dicts.get(4).unwrap().allowed, vecstr!["ZEBU"]);
+    assert_eq!(
+        report.merged,
+        vec![("ETES".to_string(), "ETES".to_string())]
+    );
+    assert_eq!(report.changed.len(), 3);
+}
+
+#[test]
+fn test_dict_set_lists_of_size() {
+    let (answers, allowed, weights) = lists_of_size(
+        vecstr!["CHAT", "ABACAS", "LOUP"],
+        vecstr!["ZEBU", "ABATIS"],
+        Some(vec![3., 2., 1.]),
+        4,
+    );
+    assert_eq!(answers, vecstr!["CHAT", "LOUP"]);
+    assert_eq!(allowed, vecstr!["ZEBU"]);
+    assert_eq!(weights, Some(vec![3., 1.]));
+
+    let path = std::env::temp_dir().join("mutsolver_test_dict_set_validate.json");
+    std::fs::write(
+        &path,
+        r#"{"answers": ["CHAT", "ABACAS", "LOUP", "CHAT"], "allowed": ["ZEBU"]}"#,
+    )
+    .unwrap();
+    let report = DictSet::validate_file(&path, 4).unwrap();
+    let other = DictSet::validate_file(&path, 6).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(report.issues.len(), 1);
+    assert!(other.is_empty());
+}
@@ -0,0 +1,63 @@
+use mutsolver_core::{attempt::Attempt, errors::Error, Dict, FeedbackMatrix, Game, Guess, Options};
+mod fixtures;
+use fixtures::{fixture_dict, fixture_testsuite};
+
+#[test]
+fn test_matrix_of_dict() {
+    let dict = Dict::new(
+        vec!["REVOLVER".to_string(), "RIVIERES".to_string()],
+        vec!["VERIFIER".to_string()],
+    )
+    .unwrap();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+
+    for word in dict.answers.iter().chain(dict.allowed.iter()) {
+        let row = matrix.row(word).unwrap();
+        assert_eq!(row.len(), dict.answers.len());
+        for (answer, pattern) in dict.answers.iter().zip(row.iter()) {
+            let attempt = Attempt::from_answer(word, answer).unwrap();
+            assert_eq!(*pattern, attempt.pattern().unwrap());
+        }
+    }
+    assert!(matrix.row("ZZZZZZZZ").is_none());
+    assert!(matrix.is_of_dict(&dict));
+    assert!(!matrix.is_of_dict(&fixture_dict()));
+
+    // Same answers and number of words, but different allowed words
+    let other = Dict::new(dict.answers.clone(), vec!["VERITIER".to_string()]).unwrap();
+    assert!(!matrix.is_of_dict(&other));
+}
+
+#[test]
+fn test_game_with_matrix() {
+    let dict = fixture_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let mut game = Game::new_with_tests(&dict, fixture_testsuite());
+    game.set_feedback_matrix(&matrix).unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Candidate("ABONDE".to_string())
+    );
+
+    game.add(Attempt::from_answer("ABONDE", "ASORTI").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("ASORTI".to_string())
+    );
+}
+
+#[test]
+fn test_game_with_unrelated_matrix() {
+    let dict = fixture_dict();
+    let other = Dict::new(vec!["ABOUTI".to_string()], vec![]).unwrap();
+    let matrix = FeedbackMatrix::of_dict(&other).unwrap();
+    let mut game = Game::new(&dict, &Options::default());
+
+    match game.set_feedback_matrix(&matrix) {
+        Err(Error::UnrelatedFeedbackMatrix) => (),
+        _ => panic!(),
+    }
+}
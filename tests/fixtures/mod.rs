@@ -1,4 +1,4 @@
-use mutsolver_core::{Dict, Test, TestSuite};
+use mutsolver_core::{Dict, Strategy, Test, TestSuite, Turn};
 
 #[allow(dead_code)]
 pub fn fixture_dict() -> Dict {
@@ -14,6 +14,21 @@ pub fn fixture_dict() -> Dict {
     .unwrap()
 }
 
+/// Answers only told apart at once by a word which cannot be the solution.
+#[allow(dead_code)]
+pub fn fixture_sacrifice_dict() -> Dict {
+    Dict::new(
+        vec![
+            "BARE".to_string(),
+            "CARE".to_string(),
+            "DARE".to_string(),
+            "FARE".to_string(),
+        ],
+        vec!["BCDF".to_string()],
+    )
+    .unwrap()
+}
+
 #[allow(dead_code)]
 pub fn fixture_testsuite() -> TestSuite {
     vec![
@@ -24,3 +39,17 @@ pub fn fixture_testsuite() -> TestSuite {
         Test::HasAtLeast('L', 1),
     ]
 }
+
+/// Strategy favoring a single word.
+#[allow(dead_code)]
+pub struct Favorite(pub &'static str);
+
+impl Strategy for Favorite {
+    fn score(&self, _turn: &Turn, word: &str) -> f64 {
+        if word == self.0 {
+            1.
+        } else {
+            0.
+        }
+    }
+}
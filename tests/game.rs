@@ -1,10 +1,12 @@
 use mutsolver_core::{
     attempt::Attempt,
+    errors::Error,
+    state::State,
     Answer::{No, Unknown, Yes},
-    Answers, Game, Guess, Options,
+    Answers, Dict, Game, Guess, Hint, Options, Scoring,
 };
 mod fixtures;
-use fixtures::{fixture_dict, fixture_testsuite};
+use fixtures::{fixture_dict, fixture_sacrifice_dict, fixture_testsuite};
 
 #[test]
 fn test_valid_game() {
@@ -14,7 +16,8 @@ fn test_valid_game() {
 
     let answer = "ASORTI";
 
-    game.add(Attempt::from_answer("ABOUTI", answer).unwrap());
+    game.add(Attempt::from_answer("ABOUTI", answer).unwrap())
+        .unwrap();
     assert_eq!(game.attempts().len(), 1);
 }
 
@@ -30,21 +33,24 @@ fn test_known_answers() {
         Answers(vec![Unknown, Unknown, Unknown, Unknown, Unknown])
     );
 
-    game.add(Attempt::from_answer("ABOUTI", "ABONDE").unwrap());
+    game.add(Attempt::from_answer("ABOUTI", "ABONDE").unwrap())
+        .unwrap();
 
     assert_eq!(
         game.known_answers().unwrap(),
         Answers(vec![Yes, Yes, No, Unknown, Unknown])
     );
 
-    game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap());
+    game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap())
+        .unwrap();
 
     assert_eq!(
         game.known_answers().unwrap(),
         Answers(vec![Yes, Yes, No, Unknown, No])
     );
 
-    game.add(Attempt::from_answer("ABONDE", "ABONDE").unwrap());
+    game.add(Attempt::from_answer("ABONDE", "ABONDE").unwrap())
+        .unwrap();
 
     assert_eq!(
         game.known_answers().unwrap(),
@@ -64,14 +70,359 @@ fn test_next_guess() {
         Guess::Candidate("ABONDE".to_string())
     );
 
-    game.add(Attempt::from_answer("ABONDE", "ASORTI").unwrap());
+    game.add(Attempt::from_answer("ABONDE", "ASORTI").unwrap())
+        .unwrap();
 
     assert_eq!(
         game.guess_next().unwrap(),
         Guess::Solution("ASORTI".to_string())
     );
 
-    game.add(Attempt::from_answer("ASORTI", "ABONDE").unwrap());
+    game.add(Attempt::from_answer("ASORTI", "ABONDE").unwrap())
+        .unwrap();
 
     assert_eq!(game.guess_next().unwrap(), Guess::NoSolution);
 }
+
+#[test]
+fn test_next_guess_entropy() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        scoring: Scoring::Entropy,
+        ..Options::default()
+    };
+
+    let mut game = Game::new(&dict, &options);
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Sacrifice("BCDF".to_string())
+    );
+
+    game.add(Attempt::from_answer("BCDF", "DARE").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("DARE".to_string())
+    );
+}
+
+#[test]
+fn test_add_invalid_size() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+
+    match game.add(Attempt::from_answer("ABOUT", "ABOND").unwrap()) {
+        Err(Error::InvalidSize(6, 5, _)) => (),
+        _ => panic!(),
+    }
+    match game.add(Attempt("ABOUTI", vec![State::Yes; 3])) {
+        Err(Error::InvalidPatternSize(6, 3, _)) => (),
+        _ => panic!(),
+    }
+    assert_eq!(game.attempts().len(), 0);
+}
+
+#[test]
+fn test_add_unknown_word() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+
+    match game.add(Attempt::from_answer("ZOZOTE", "ABONDE").unwrap()) {
+        Err(Error::UnknownWord(word)) => assert_eq!(word, "ZOZOTE"),
+        _ => panic!(),
+    }
+    assert_eq!(game.attempts().len(), 0);
+
+    let options = Options {
+        allow_unknown_words: true,
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    game.add(Attempt::from_answer("ZOZOTE", "ABONDE").unwrap())
+        .unwrap();
+    assert_eq!(game.attempts().len(), 1);
+}
+
+#[test]
+fn test_add_inconsistent_attempt() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    game.add(Attempt::from_answer("ABOUTI", "ABONDE").unwrap())
+        .unwrap();
+
+    // 'B' was found well placed at position 1
+    match game.add(Attempt(
+        "ABSOLU",
+        vec![
+            State::Yes,
+            State::No,
+            State::No,
+            State::Meh,
+            State::No,
+            State::No,
+        ],
+    )) {
+        Err(Error::InconsistentAttempt(word)) => assert_eq!(word, "ABSOLU"),
+        _ => panic!(),
+    }
+    // 'U' was found absent
+    match game.add(Attempt(
+        "ABSOLU",
+        vec![
+            State::Yes,
+            State::Yes,
+            State::No,
+            State::Meh,
+            State::No,
+            State::Meh,
+        ],
+    )) {
+        Err(Error::InconsistentAttempt(_)) => (),
+        _ => panic!(),
+    }
+    assert_eq!(game.attempts().len(), 1);
+
+    game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap())
+        .unwrap();
+    assert_eq!(game.attempts().len(), 2);
+}
+
+#[test]
+fn test_undo_truncate() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+
+    assert!(game.undo().is_none());
+
+    game.add(Attempt::from_answer("ABOUTI", "ASORTI").unwrap())
+        .unwrap();
+    game.add(Attempt::from_answer("ABONDE", "ASORTI").unwrap())
+        .unwrap();
+    game.add(Attempt::from_answer("ABSOLU", "ASORTI").unwrap())
+        .unwrap();
+
+    let attempt = game.undo().unwrap();
+    assert_eq!(attempt.0, "ABSOLU");
+    assert_eq!(game.attempts().len(), 2);
+
+    game.truncate(5);
+    assert_eq!(game.attempts().len(), 2);
+    game.truncate(1);
+    assert_eq!(game.attempts().len(), 1);
+    assert_eq!(game.attempts()[0].0, "ABOUTI");
+}
+
+#[test]
+fn test_replace() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+
+    // Feedbacks are mistyped as if solution was ABONDE
+    game.add(Attempt::from_answer("ABOUTI", "ABONDE").unwrap())
+        .unwrap();
+    game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap())
+        .unwrap();
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("ABONDE".to_string())
+    );
+
+    // Replacement must remain consistent with other attempts
+    match game.replace(0, Attempt::from_answer("ABOUTI", "ASORTI").unwrap()) {
+        Err(Error::InconsistentAttempt(word)) => assert_eq!(word, "ABOUTI"),
+        _ => panic!(),
+    }
+    match game.replace(2, Attempt::from_answer("ABSOLU", "ASORTI").unwrap()) {
+        Err(Error::InvalidAttemptIndex(2, 2)) => (),
+        _ => panic!(),
+    }
+
+    game.truncate(1);
+    match game.replace(0, Attempt::from_answer("ABOUTI", "ASORTI").unwrap()) {
+        Ok(previous) => assert!(previous.is_compatible("ABONDE")),
+        _ => panic!(),
+    }
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("ASORTI".to_string())
+    );
+}
+
+#[test]
+fn test_revealed_letters() {
+    let dict = fixture_dict();
+    let options = Options {
+        revealed: vec![(1, 'S')],
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+
+    assert!(game.is_playable("ASORTI"));
+    assert!(!game.is_playable("ABOUTI"));
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("ASORTI".to_string())
+    );
+
+    match game.add(Attempt::from_answer("ABOUTI", "ASORTI").unwrap()) {
+        Err(Error::MissingRevealedLetter(1, 'S', word)) => assert_eq!(word, "ABOUTI"),
+        _ => panic!(),
+    }
+    game.add(Attempt::from_answer("ASORTI", "ASORTI").unwrap())
+        .unwrap();
+}
+
+#[test]
+fn test_invalid_revealed_letters() {
+    let dict = fixture_dict();
+    for revealed in [(6, 'A'), (0, 'a'), (1, 'É')] {
+        let options = Options {
+            revealed: vec![revealed],
+            ..Options::default()
+        };
+        let mut game = Game::new(&dict, &options);
+
+        match game.guess_next() {
+            Err(Error::InvalidRevealedLetter(position, letter)) => {
+                assert_eq!((position, letter), revealed)
+            }
+            _ => panic!(),
+        }
+        match game.candidates() {
+            Err(Error::InvalidRevealedLetter(..)) => (),
+            _ => panic!(),
+        }
+        match game.add(Attempt::from_answer("ABOUTI", "ABONDE").unwrap()) {
+            Err(Error::InvalidRevealedLetter(..)) => (),
+            _ => panic!(),
+        }
+    }
+}
+
+#[test]
+fn test_revealed_letters_sacrifice() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        scoring: Scoring::Entropy,
+        revealed: vec![(3, 'E')],
+        ..Options::default()
+    };
+    let game = Game::new(&dict, &options);
+
+    // Best sacrifice does not end with revealed letter
+    match game.guess_next().unwrap() {
+        Guess::Candidate(_) => (),
+        _ => panic!(),
+    }
+    assert_eq!(game.knowledge().placed[3], Some('E'));
+}
+
+#[test]
+fn test_hard_mode() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        scoring: Scoring::Entropy,
+        ..Options::default()
+    };
+    let hard_options = Options {
+        hard_mode: true,
+        ..options.clone()
+    };
+
+    let mut game = Game::new(&dict, &options);
+    let mut hard_game = Game::new(&dict, &hard_options);
+    for game in [&mut game, &mut hard_game] {
+        game.add(Attempt::from_answer("BARE", "DARE").unwrap())
+            .unwrap();
+    }
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Sacrifice("BCDF".to_string())
+    );
+    match hard_game.guess_next().unwrap() {
+        Guess::Candidate(_) => (),
+        _ => panic!(),
+    }
+
+    assert_eq!(hard_game.violated_hint("BCDF"), Some(Hint::Placed(1, 'A')));
+    assert_eq!(hard_game.violated_hint("CARE"), None);
+    match hard_game.add(Attempt::from_answer("BCDF", "DARE").unwrap()) {
+        Err(Error::IgnoredHint(Hint::Placed(1, 'A'), word)) => assert_eq!(word, "BCDF"),
+        _ => panic!(),
+    }
+    game.add(Attempt::from_answer("BCDF", "DARE").unwrap())
+        .unwrap();
+}
+
+#[test]
+fn test_hard_mode_replace() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        hard_mode: true,
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    game.add(Attempt::from_answer("FARE", "DARE").unwrap())
+        .unwrap();
+    game.add(Attempt::from_answer("BARE", "DARE").unwrap())
+        .unwrap();
+
+    // The following attempt does not reuse the letter D found by the replacement
+    match game.replace(0, Attempt::from_answer("BCDF", "DARE").unwrap()) {
+        Err(Error::IgnoredHint(Hint::Present('D', 1), word)) => assert_eq!(word, "BARE"),
+        _ => panic!(),
+    }
+    assert_eq!(game.attempts()[0].0, "FARE");
+
+    match game.replace(0, Attempt::from_answer("CARE", "DARE").unwrap()) {
+        Ok(previous) => assert_eq!(previous.0, "FARE"),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_candidates() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    assert_eq!(game.candidates().unwrap().len(), 4);
+
+    game.add(Attempt::from_answer("ABSOLU", "ABONDE").unwrap())
+        .unwrap();
+    assert_eq!(game.candidates().unwrap(), vec!["ABONDE"]);
+}
+
+#[test]
+fn test_weighted_guess() {
+    let answers = fixture_sacrifice_dict().answers;
+
+    // Equally likely answers are symmetric, the last one being suggested
+    let dict = Dict::new(answers.clone(), vec![]).unwrap();
+    for scoring in [Scoring::Heuristic, Scoring::Entropy, Scoring::Minimax] {
+        let options = Options {
+            scoring,
+            ..Options::default()
+        };
+        let game = Game::new(&dict, &options);
+        assert_eq!(
+            game.guess_next().unwrap(),
+            Guess::Candidate("FARE".to_string())
+        );
+    }
+
+    // The most likely answer is preferred
+    let dict = Dict::new_with_weights(answers, vec![], vec![1., 10., 1., 1.]).unwrap();
+    for scoring in [Scoring::Heuristic, Scoring::Entropy, Scoring::Minimax] {
+        let options = Options {
+            scoring,
+            ..Options::default()
+        };
+        let game = Game::new(&dict, &options);
+        assert_eq!(
+            game.guess_next().unwrap(),
+            Guess::Candidate("CARE".to_string())
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use mutsolver_core::{
+    attempt::Attempt,
+    state::State::{Meh, No, Yes},
+    Answer, Answers, Dict, Game, Hint, Knowledge, Test,
+};
+
+#[test]
+fn test_knowledge_of_attempts() {
+    let attempts = vec![
+        Attempt("ABBCC", vec![Yes, Meh, No, No, No]),
+        Attempt("DEFAB", vec![No, No, No, Meh, Meh]),
+    ];
+    let knowledge = Knowledge::of_attempts(&attempts, 5);
+
+    assert_eq!(knowledge.placed, vec![Some('A'), None, None, None, None]);
+    assert!(knowledge.excluded[1].contains(&'B'));
+    assert!(knowledge.excluded[4].contains(&'B'));
+    assert_eq!(knowledge.min_count('A'), 1);
+    assert_eq!(knowledge.max_count('B'), 1);
+    assert_eq!(knowledge.max_count('C'), 0);
+    assert_eq!(knowledge.min_count('Z'), 0);
+    assert_eq!(knowledge.max_count('Z'), 4);
+}
+
+#[test]
+fn test_knowledge_prefix_suffix() {
+    // Each letter of prefix and suffix is found by a different attempt
+    let attempts = vec![
+        Attempt("ABCDE", vec![Yes, No, No, No, Yes]),
+        Attempt("FGHIJ", vec![No, Yes, No, Yes, No]),
+    ];
+    let knowledge = Knowledge::of_attempts(&attempts, 5);
+
+    assert_eq!(
+        knowledge.answers(&Test::HasPrefix("AG".to_string())),
+        Answer::Yes
+    );
+    assert_eq!(
+        knowledge.answers(&Test::HasSuffix("IE".to_string())),
+        Answer::Yes
+    );
+    assert_eq!(
+        knowledge.answers(&Test::HasPrefix("AGK".to_string())),
+        Answer::Unknown
+    );
+    assert_eq!(
+        knowledge.answers(&Test::HasPrefix("AGB".to_string())),
+        Answer::No
+    );
+    assert_eq!(
+        knowledge.answers(&Test::HasSuffix("XE".to_string())),
+        Answer::No
+    );
+}
+
+#[test]
+fn test_knowledge_counts() {
+    // 'E' is found well placed twice by different attempts
+    let attempts = vec![
+        Attempt("EAAAA", vec![Yes, No, No, No, No]),
+        Attempt("BBBBE", vec![No, No, No, No, Yes]),
+    ];
+    let knowledge = Knowledge::of_attempts(&attempts, 5);
+
+    assert_eq!(knowledge.answers(&Test::HasAtLeast('E', 2)), Answer::Yes);
+    assert_eq!(knowledge.answers(&Test::HasAtMost('E', 1)), Answer::No);
+    assert_eq!(knowledge.answers(&Test::HasAtMost('E', 5)), Answer::Yes);
+    // Only 3 positions remain for 'C'
+    assert_eq!(knowledge.answers(&Test::HasAtMost('C', 3)), Answer::Yes);
+    assert_eq!(knowledge.answers(&Test::HasAtLeast('C', 4)), Answer::No);
+    assert_eq!(
+        knowledge.answers(&Test::HasAtLeast('C', 1)),
+        Answer::Unknown
+    );
+    assert_eq!(knowledge.answers(&Test::At('A', 2)), Answer::No);
+}
+
+#[test]
+fn test_known_answers_combined() {
+    let dict = Dict::new(
+        vec!["AZZZ".to_string(), "ZBZZ".to_string(), "ABCD".to_string()],
+        vec![],
+    )
+    .unwrap();
+    let tests = vec![Test::HasPrefix("AB".to_string()), Test::HasAtMost('Z', 0)];
+    let mut game = Game::new_with_tests(&dict, tests);
+
+    // Neither attempt answers the prefix test alone
+    game.add(Attempt::from_answer("AZZZ", "ABCD").unwrap())
+        .unwrap();
+    assert_eq!(
+        game.known_answers().unwrap(),
+        Answers(vec![Answer::Unknown, Answer::Yes])
+    );
+    game.add(Attempt::from_answer("ZBZZ", "ABCD").unwrap())
+        .unwrap();
+    assert_eq!(
+        game.known_answers().unwrap(),
+        Answers(vec![Answer::Yes, Answer::Yes])
+    );
+}
+
+#[test]
+fn test_knowledge_hints() {
+    let attempts = vec![
+        Attempt("EERIE", vec![Yes, Meh, No, No, No]),
+        Attempt("TREAT", vec![No, No, Meh, Yes, No]),
+    ];
+    let knowledge = Knowledge::of_attempts(&attempts, 5);
+
+    assert_eq!(
+        knowledge.hints(),
+        vec![
+            Hint::Placed(0, 'E'),
+            Hint::Placed(3, 'A'),
+            Hint::Present('A', 1),
+            Hint::Present('E', 2),
+        ]
+    );
+    assert_eq!(knowledge.violated_hint("EAGLE"), Some(Hint::Placed(3, 'A')));
+    assert_eq!(
+        knowledge.violated_hint("EMBAY"),
+        Some(Hint::Present('E', 2))
+    );
+    assert_eq!(knowledge.violated_hint("ELEAN"), None);
+}
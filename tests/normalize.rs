@@ -0,0 +1,25 @@
+use mutsolver_core::normalize::normalize;
+
+#[test]
+fn test_normalize_diacritics() {
+    assert_eq!(normalize("été"), "ETE");
+    assert_eq!(normalize("Garçon"), "GARCON");
+    assert_eq!(normalize("MAÏS"), "MAIS");
+    assert_eq!(normalize("noël"), "NOEL");
+    assert_eq!(normalize("àâäéèêëîïôöùûüÿç"), "AAAEEEEIIOOUUUYC");
+}
+
+#[test]
+fn test_normalize_ligatures() {
+    assert_eq!(normalize("cœur"), "COEUR");
+    assert_eq!(normalize("ŒUVRE"), "OEUVRE");
+    assert_eq!(normalize("ex æquo"), "EX AEQUO");
+}
+
+#[test]
+fn test_normalize_unchanged() {
+    assert_eq!(normalize("ABACAS"), "ABACAS");
+    assert_eq!(normalize("abacas"), "ABACAS");
+    // Characters without ASCII equivalent are kept
+    assert_eq!(normalize("a-b"), "A-B");
+}
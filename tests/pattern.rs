@@ -0,0 +1,69 @@
+use mutsolver_core::{
+    attempt::Attempt,
+    errors::Error,
+    state::{
+        State::{Meh, No, Yes},
+        States,
+    },
+    Pattern,
+};
+use std::collections::HashSet;
+
+#[test]
+fn test_pattern_states() {
+    let states = vec![Yes, No, Yes, No, Meh, Meh, Yes, No];
+    let pattern = Pattern::try_from(&states).unwrap();
+    assert_eq!(pattern.size(), 8);
+    assert_eq!(pattern.states(), states);
+    assert_eq!(States::from(pattern), states);
+
+    // Trailing "No" states are kept
+    let pattern = Pattern::try_from(&vec![No, No, No]).unwrap();
+    assert_eq!(pattern.states(), vec![No, No, No]);
+    assert_ne!(pattern, Pattern::try_from(&vec![No, No]).unwrap());
+}
+
+#[test]
+fn test_pattern_too_long() {
+    let pattern = Pattern::try_from(&vec![Yes; Pattern::MAX_SIZE]).unwrap();
+    assert!(pattern.is_solved());
+    match Pattern::try_from(&vec![Yes; Pattern::MAX_SIZE + 1]) {
+        Err(Error::PatternTooLong(20)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_pattern_solved() {
+    assert!(Pattern::solved(5).is_solved());
+    assert_eq!(Pattern::solved(3).states(), vec![Yes, Yes, Yes]);
+    let attempt = Attempt::from_answer("ABCD", "ABDC").unwrap();
+    assert!(!attempt.pattern().unwrap().is_solved());
+}
+
+#[test]
+fn test_pattern_display() {
+    let pattern = Pattern::try_from(&vec![Yes, Meh, No]).unwrap();
+    assert_eq!(format!("{}", pattern), "🟥🟡🟦");
+}
+
+#[test]
+fn test_pattern_hash() {
+    let patterns: HashSet<Pattern> = ["ABOUTI", "ABONDE", "ASORTI", "ABSOLU"]
+        .iter()
+        .map(|answer| Attempt::from_answer("ABOUTI", answer).unwrap())
+        .map(|attempt| attempt.pattern().unwrap())
+        .collect();
+    assert_eq!(patterns.len(), 4);
+}
+
+#[test]
+fn test_pattern_serde() {
+    let pattern = Pattern::try_from(&vec![Yes, Meh, No, No]).unwrap();
+    let json = serde_json::to_string(&pattern).unwrap();
+    assert_eq!(json, "\"2100\"");
+    assert_eq!(serde_json::from_str::<Pattern>(&json).unwrap(), pattern);
+
+    assert!(serde_json::from_str::<Pattern>("\"2130\"").is_err());
+    assert!(serde_json::from_str::<Pattern>("86").is_err());
+}
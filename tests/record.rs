@@ -0,0 +1,76 @@
+use mutsolver_core::{
+    attempt::Attempt, errors::Error, Dict, Game, GameRecord, Guess, Options, Scoring,
+};
+mod fixtures;
+use fixtures::fixture_dict;
+
+#[test]
+fn test_record_roundtrip() {
+    let dict = fixture_dict();
+    let options = Options {
+        scoring: Scoring::Entropy,
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    game.add(Attempt::from_answer("ABOUTI", "ASORTI").unwrap())
+        .unwrap();
+
+    let record = game.record().unwrap();
+    assert_eq!(record.word_size, 6);
+    assert_eq!(record.attempts.len(), 1);
+    assert_eq!(record.attempts[0].word, "ABOUTI");
+    assert_eq!(record.fingerprint, dict.fingerprint());
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert!(json.contains("\"pattern\":\"202022\""));
+    let record: GameRecord = serde_json::from_str(&json).unwrap();
+
+    let resumed = Game::from_record(&dict, &record).unwrap();
+    assert_eq!(resumed.options(), &options);
+    assert_eq!(resumed.attempts()[0].1, game.attempts()[0].1);
+    assert_eq!(
+        resumed.guess_next().unwrap(),
+        Guess::Solution("ASORTI".to_string())
+    );
+}
+
+#[test]
+fn test_record_file() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    game.add(Attempt::from_answer("ABONDE", "ABSOLU").unwrap())
+        .unwrap();
+
+    let path = std::env::temp_dir().join("mutsolver_test_record_file.json");
+    game.record().unwrap().to_file(&path).unwrap();
+    let record = GameRecord::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(record, game.record().unwrap());
+}
+
+#[test]
+fn test_record_unrelated_dict() {
+    let dict = fixture_dict();
+    let game = Game::new(&dict, &Options::default());
+    let record = game.record().unwrap();
+
+    let other = Dict::new(vec!["ABOUTI".to_string()], vec![]).unwrap();
+    match Game::from_record(&other, &record) {
+        Err(Error::UnrelatedRecord(_, _)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_record_invalid_size() {
+    let dict = fixture_dict();
+    let game = Game::new(&dict, &Options::default());
+    let mut record = game.record().unwrap();
+    record.word_size += 1;
+
+    match Game::from_record(&dict, &record) {
+        Err(Error::InvalidRecordSize(6, 7)) => (),
+        _ => panic!(),
+    }
+}
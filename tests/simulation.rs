@@ -0,0 +1,72 @@
+use mutsolver_core::{attempt::Attempt, simulate, Dict, Game, Options};
+mod fixtures;
+use fixtures::{fixture_dict, fixture_sacrifice_dict};
+
+#[test]
+fn test_simulate() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    let report = simulate(&mut game, 6).unwrap();
+
+    assert_eq!(report.results.len(), dict.answers.len());
+    assert!(report.results.iter().all(|result| result.solved));
+    assert!(report
+        .results
+        .iter()
+        .all(|result| result.guesses.last() == Some(&result.answer)));
+    assert_eq!(
+        report.distribution().iter().sum::<usize>(),
+        dict.answers.len()
+    );
+    assert!(report.failures().is_empty());
+    assert!(report.average() >= 1.);
+    assert!(game.attempts().is_empty());
+}
+
+#[test]
+fn test_simulate_failures() {
+    let dict = Dict::new(fixture_sacrifice_dict().answers, Vec::new()).unwrap();
+    let mut game = Game::new(&dict, &Options::default());
+    let report = simulate(&mut game, 2).unwrap();
+
+    // Without sacrifice, at most two answers are found in two attempts
+    assert_eq!(report.distribution().len(), 2);
+    assert_eq!(report.distribution().iter().sum::<usize>(), 2);
+    assert_eq!(report.failures().len(), 2);
+    assert!(report
+        .failures()
+        .iter()
+        .all(|failure| failure.guesses.len() == 2));
+    assert_eq!(report.average(), 1.5);
+}
+
+#[test]
+fn test_simulate_revealed() {
+    let dict = Dict::new(
+        vec![
+            "BARE".to_string(),
+            "CARE".to_string(),
+            "CORE".to_string(),
+            "DARE".to_string(),
+        ],
+        Vec::new(),
+    )
+    .unwrap();
+    let options = Options {
+        revealed: vec![(0, 'C')],
+        ..Options::default()
+    };
+    let mut game = Game::new(&dict, &options);
+    game.add(Attempt::from_answer("CARE", "CORE").unwrap())
+        .unwrap();
+    let report = simulate(&mut game, 6).unwrap();
+
+    // Each answer is played with its own first letter revealed
+    assert!(report.failures().is_empty());
+    assert_eq!(report.results[0].guesses, vec!["BARE".to_string()]);
+
+    // Attempts and revealed letters of the game are restored
+    assert_eq!(game.options(), &options);
+    assert_eq!(game.attempts().len(), 1);
+    assert_eq!(game.attempts()[0].0, "CARE");
+}
@@ -0,0 +1,164 @@
+use mutsolver_core::{
+    attempt::Attempt,
+    errors::Error,
+    strategy::{Entropy, Heuristic, Minimax},
+    Game, Guess, OpeningBook, Options, Scoring, Strategy, Turn,
+};
+mod fixtures;
+use fixtures::{fixture_dict, fixture_sacrifice_dict, fixture_testsuite, Favorite};
+
+#[test]
+fn test_custom_strategy() {
+    let dict = fixture_dict();
+    let mut game =
+        Game::new_with_strategy(&dict, &Options::default(), Box::new(Favorite("ASORTI")));
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Candidate("ASORTI".to_string())
+    );
+
+    game.add(Attempt::from_answer("ASORTI", "ABONDE").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("ABONDE".to_string())
+    );
+}
+
+#[test]
+fn test_entropy_score() {
+    let dict = fixture_dict();
+    let tests = fixture_testsuite();
+    let turn = Turn {
+        tests: &tests,
+        compatible: dict.answers.iter().collect(),
+        indices: (0..dict.answers.len()).collect(),
+        weights: vec![1.; dict.answers.len()],
+        feedback: None,
+        test_weights: vec![0.; tests.len()],
+    };
+
+    // Each word of the fixture produces distinct states for each answer
+    for word in dict.answers.iter() {
+        assert_eq!(Entropy.score(&turn, word), 2.);
+    }
+    // A word sharing no letter with any answer gives no information
+    assert_eq!(Entropy.score(&turn, "ZZZZZZ"), 0.);
+}
+
+#[test]
+fn test_heuristic_score() {
+    let dict = fixture_dict();
+    let tests = fixture_testsuite();
+    let turn = Turn {
+        tests: &tests,
+        compatible: dict.answers.iter().collect(),
+        indices: (0..dict.answers.len()).collect(),
+        weights: vec![1.; dict.answers.len()],
+        feedback: None,
+        test_weights: vec![1., 0., 0., 2., 0.],
+    };
+
+    // Tests At('B', 1) and At('D', 4) are both run by ABONDE
+    assert_eq!(Heuristic.score(&turn, "ABONDE"), 78.);
+    assert_eq!(Heuristic.score(&turn, "ASORTI"), 3.);
+}
+
+#[test]
+fn test_minimax_score() {
+    let dict = fixture_dict();
+    let tests = fixture_testsuite();
+    let turn = Turn {
+        tests: &tests,
+        compatible: dict.answers.iter().collect(),
+        indices: (0..dict.answers.len()).collect(),
+        weights: vec![1.; dict.answers.len()],
+        feedback: None,
+        test_weights: vec![0.; tests.len()],
+    };
+
+    assert_eq!(Minimax.score(&turn, "ABOUTI"), -1.);
+    assert_eq!(Minimax.score(&turn, "ZZZZZZ"), -4.);
+}
+
+#[test]
+fn test_custom_strategy_not_recorded() {
+    let dict = fixture_dict();
+    let mut game =
+        Game::new_with_strategy(&dict, &Options::default(), Box::new(Favorite("ASORTI")));
+    assert!(game.has_custom_strategy());
+    assert!(!Game::new(&dict, &Options::default()).has_custom_strategy());
+
+    // Options do not tell the strategy, which would be lost
+    match game.record() {
+        Err(Error::CustomStrategy) => (),
+        _ => panic!(),
+    }
+    match OpeningBook::of_game(&mut game, false) {
+        Err(Error::CustomStrategy) => (),
+        _ => panic!(),
+    }
+    let book = OpeningBook::of_game(&mut Game::new(&dict, &Options::default()), false).unwrap();
+    match game.set_opening_book(&book) {
+        Err(Error::CustomStrategy) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_weighted_scores() {
+    let dict = fixture_dict();
+    let tests = fixture_testsuite();
+    let turn = Turn {
+        tests: &tests,
+        compatible: dict.answers.iter().collect(),
+        indices: (0..dict.answers.len()).collect(),
+        weights: vec![1., 1., 1., 5.],
+        feedback: None,
+        test_weights: vec![0.; tests.len()],
+    };
+
+    assert_eq!(turn.total_weight(), 8.);
+    assert_eq!(
+        turn.buckets("ZZZZZZ").into_values().collect::<Vec<f64>>(),
+        vec![8.]
+    );
+    assert_eq!(
+        turn.bucket_sizes("ZZZZZZ")
+            .into_values()
+            .collect::<Vec<usize>>(),
+        vec![4]
+    );
+    // Minimax only depends on the size of groups, not on their weight
+    assert_eq!(Minimax.score(&turn, "ABOUTI"), -1.);
+    assert_eq!(Minimax.score(&turn, "ZZZZZZ"), -4.);
+    // Distinct states for each answer, the last one being more likely
+    let expected = -3. * 0.125 * 0.125f64.log2() - 0.625 * 0.625f64.log2();
+    assert!((Entropy.score(&turn, "ABOUTI") - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_minimax_game() {
+    let dict = fixture_sacrifice_dict();
+    let options = Options {
+        scoring: Scoring::Minimax,
+        ..Options::default()
+    };
+
+    let mut game = Game::new(&dict, &options);
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Sacrifice("BCDF".to_string())
+    );
+
+    game.add(Attempt::from_answer("BCDF", "FARE").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("FARE".to_string())
+    );
+}
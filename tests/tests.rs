@@ -60,6 +60,7 @@ fn get_for_word() {
     let options = Options {
         tests_keep_ratio: 0.01,
         tests_xfix_lengths: 2,
+        ..Options::default()
     };
     let test_suite = Test::for_word("SEER", &options);
 
@@ -0,0 +1,228 @@
+use mutsolver_core::{
+    attempt::Attempt, errors::Error, simulate, state::Alphabet, DecisionTree, Dict, FeedbackMatrix,
+    Game, Guess, Options,
+};
+mod fixtures;
+use fixtures::{fixture_dict, fixture_sacrifice_dict, Favorite};
+
+#[test]
+fn test_optimal_tree() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+
+    // BCDF tells apart every answer
+    assert_eq!(tree.guess, "BCDF");
+    assert_eq!(tree.answers, 4);
+    assert!(!tree.is_candidate());
+    assert_eq!(tree.branches.len(), 4);
+    assert_eq!(tree.cost(), 8);
+    assert_eq!(tree.depth(), 2);
+    assert_eq!(tree.expected_guesses(), 2.);
+
+    let pattern = Attempt::from_answer("BCDF", "DARE")
+        .unwrap()
+        .pattern()
+        .unwrap();
+    let branch = tree.branch(&pattern).unwrap();
+    assert_eq!(branch.guess, "DARE");
+    assert_eq!(branch.answers, 1);
+    assert!(branch.is_candidate());
+}
+
+#[test]
+fn test_optimal_tree_beats_heuristic() {
+    let dict = fixture_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+
+    let mut game = Game::new(&dict, &Options::default());
+    let report = simulate(&mut game, 6).unwrap();
+    let heuristic_cost: usize = report
+        .results
+        .iter()
+        .map(|result| result.guesses.len())
+        .sum();
+
+    assert_eq!(tree.answers, dict.answers.len());
+    assert!(tree.cost() <= heuristic_cost);
+}
+
+#[test]
+fn test_optimal_tree_unrelated_matrix() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&fixture_dict()).unwrap();
+
+    match DecisionTree::optimal(&dict, &matrix) {
+        Err(Error::UnrelatedFeedbackMatrix) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_game_with_tree() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+    let mut game = Game::new(&dict, &Options::default());
+    game.set_decision_tree(&tree).unwrap();
+
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Sacrifice("BCDF".to_string())
+    );
+
+    game.add(Attempt::from_answer("BCDF", "DARE").unwrap())
+        .unwrap();
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Solution("DARE".to_string())
+    );
+
+    // Attempts leaving the tree fall back to the strategy of the game
+    let mut other = Game::new(&dict, &Options::default());
+    game.truncate(0);
+    game.add(Attempt::from_answer("CARE", "FARE").unwrap())
+        .unwrap();
+    other
+        .add(Attempt::from_answer("CARE", "FARE").unwrap())
+        .unwrap();
+    assert!(tree.lookup(game.attempts()).is_none());
+    assert_eq!(game.guess_next().unwrap(), other.guess_next().unwrap());
+}
+
+#[test]
+fn test_game_with_unrelated_tree() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+
+    // Dict with the words of the tree, and another answer
+    let mut answers = dict.answers.clone();
+    answers.push("DOME".to_string());
+    let other = Dict::new(answers, dict.allowed.clone()).unwrap();
+    let mut game = Game::new(&other, &Options::default());
+    match game.set_decision_tree(&tree) {
+        Err(Error::UnrelatedDecisionTree) => (),
+        _ => panic!(),
+    }
+
+    // Tree of the dict guessing a word outside of it
+    let mut tree = tree.clone();
+    tree.guess = "BCDG".to_string();
+    let mut game = Game::new(&dict, &Options::default());
+    match game.set_decision_tree(&tree) {
+        Err(Error::UnknownWord(word)) if word == "BCDG" => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_game_with_tree_solution() {
+    let dict = fixture_sacrifice_dict();
+    let tree = DecisionTree {
+        fingerprint: dict.fingerprint(),
+        guess: "BARE".to_string(),
+        answers: 1,
+        branches: vec![],
+    };
+    let mut game = Game::new(&dict, &Options::default());
+    game.set_decision_tree(&tree).unwrap();
+
+    // The solution is only given when the game has a single candidate left
+    assert_eq!(
+        game.guess_next().unwrap(),
+        Guess::Candidate("BARE".to_string())
+    );
+}
+
+#[test]
+fn test_tree_serde() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_eq!(serde_json::from_str::<DecisionTree>(&json).unwrap(), tree);
+}
+
+#[test]
+fn test_tree_of_game() {
+    let dict = fixture_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    let tree = DecisionTree::of_game(&mut game).unwrap();
+    let report = simulate(&mut game, 6).unwrap();
+
+    // Each answer is reached by the guesses of its simulated game
+    assert_eq!(tree.answers, dict.answers.len());
+    for result in report.results.iter() {
+        let mut node = &tree;
+        for guess in result.guesses.iter().take(result.guesses.len() - 1) {
+            assert_eq!(&node.guess, guess);
+            let pattern = Attempt::from_answer(guess, &result.answer)
+                .unwrap()
+                .pattern()
+                .unwrap();
+            node = node.branch(&pattern).unwrap();
+        }
+        assert_eq!(node.guess, result.answer);
+    }
+    assert_eq!(
+        tree.cost(),
+        report
+            .results
+            .iter()
+            .map(|result| result.guesses.len())
+            .sum::<usize>()
+    );
+    assert!(game.attempts().is_empty());
+}
+
+#[test]
+fn test_tree_of_game_from_attempts() {
+    let dict = fixture_sacrifice_dict();
+    let mut game = Game::new(&dict, &Options::default());
+    game.add(Attempt::from_answer("BCDF", "CARE").unwrap())
+        .unwrap();
+    let tree = DecisionTree::of_game(&mut game).unwrap();
+
+    assert_eq!(tree.guess, "CARE");
+    assert_eq!(tree.answers, 1);
+    assert!(tree.branches.is_empty());
+    assert_eq!(game.attempts().len(), 1);
+}
+
+#[test]
+fn test_tree_of_game_no_progress() {
+    let dict = Dict::new(
+        vec!["BARE".to_string(), "CARE".to_string()],
+        vec!["XXXX".to_string()],
+    )
+    .unwrap();
+    let mut game = Game::new_with_strategy(&dict, &Options::default(), Box::new(Favorite("XXXX")));
+
+    match DecisionTree::of_game(&mut game) {
+        Err(Error::NoProgress(2)) => (),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_tree_export() {
+    let dict = fixture_sacrifice_dict();
+    let matrix = FeedbackMatrix::of_dict(&dict).unwrap();
+    let tree = DecisionTree::optimal(&dict, &matrix).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&tree.to_json(&Alphabet::SIGNS)).unwrap();
+    assert_eq!(json["guess"], "BCDF");
+    assert_eq!(json["answers"], 4);
+    assert_eq!(json["branches"]["-?--"]["guess"], "CARE");
+    assert_eq!(json["branches"]["+---"]["answers"], 1);
+
+    let dot = tree.to_dot(&Alphabet::SIGNS);
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("n0 [label=\"BCDF (4)\", style=dashed];"));
+    assert!(dot.contains("n0 -> n1 [label=\"+---\"];"));
+    assert_eq!(dot.matches("->").count(), 4);
+}